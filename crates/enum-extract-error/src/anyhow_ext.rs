@@ -0,0 +1,56 @@
+use std::fmt;
+
+use crate::EnumExtractError;
+
+/// Extension methods for converting the results of extractions into [`anyhow::Result`].
+///
+/// The value held by an [`EnumExtractValueError`](crate::EnumExtractValueError) is dropped,
+/// so it does not need to be `Send`, `Sync` or `'static`.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::{AnyhowResultExt, EnumExtractError};
+///
+/// fn extract_source() -> Result<String, EnumExtractError> {
+///     // normally returned by a function generated with `#[derive(EnumExtract)]`
+///     Err(EnumExtractError::new("File", "Url"))
+/// }
+///
+/// let error = extract_source().into_anyhow("failed to read config").unwrap_err();
+/// assert_eq!(error.to_string(), "failed to read config");
+/// assert_eq!(error.root_cause().to_string(), "expected File, got Url");
+/// ```
+pub trait AnyhowResultExt<U> {
+    /// Converts the error into an [`anyhow::Error`] with `context` added.
+    fn into_anyhow<C>(self, context: C) -> anyhow::Result<U>
+    where
+        C: fmt::Display + Send + Sync + 'static;
+
+    /// Converts the error into an [`anyhow::Error`] with the context returned by `f` added,
+    /// which is only called if there is an error.
+    fn with_anyhow_context<C, F>(self, f: F) -> anyhow::Result<U>
+    where
+        C: fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> C;
+}
+
+impl<U, E> AnyhowResultExt<U> for Result<U, E>
+where
+    E: Into<EnumExtractError>,
+{
+    fn into_anyhow<C>(self, context: C) -> anyhow::Result<U>
+    where
+        C: fmt::Display + Send + Sync + 'static,
+    {
+        self.map_err(|error| anyhow::Error::new(error.into()).context(context))
+    }
+
+    fn with_anyhow_context<C, F>(self, f: F) -> anyhow::Result<U>
+    where
+        C: fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|error| anyhow::Error::new(error.into()).context(f()))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::EnumExtractValueError;
+
+/// Extension methods for results that hold on to the value on failure.
+pub trait ValueResultExt<U, T> {
+    /// Attempts another extraction on the value if this one failed.
+    ///
+    /// If both extractions fail, the returned error lists every variant that was attempted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use enum_extract_error::{EnumExtractValueError, ValueResultExt};
+    ///
+    /// # #[derive(Debug)]
+    /// enum Number {
+    ///     One(u32),
+    ///     Two(u32),
+    ///     Three,
+    /// }
+    ///
+    /// impl Number {
+    ///     // normally generated by `#[derive(EnumExtract)]`
+    ///     fn into_one(self) -> Result<u32, EnumExtractValueError<Self>> {
+    ///         match self {
+    ///             Number::One(inner) => Ok(inner),
+    ///             _ => Err(EnumExtractValueError::new("One", "Three", self)),
+    ///         }
+    ///     }
+    ///
+    ///     fn into_two(self) -> Result<u32, EnumExtractValueError<Self>> {
+    ///         match self {
+    ///             Number::Two(inner) => Ok(inner),
+    ///             _ => Err(EnumExtractValueError::new("Two", "Three", self)),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let error = Number::Three.into_one().or_try(Number::into_two).unwrap_err();
+    /// assert_eq!(error.to_string(), "expected One or Two, got Three");
+    /// ```
+    fn or_try<F>(self, f: F) -> Result<U, EnumExtractValueError<T>>
+    where
+        F: FnOnce(T) -> Result<U, EnumExtractValueError<T>>;
+}
+
+impl<U, T> ValueResultExt<U, T> for Result<U, EnumExtractValueError<T>> {
+    fn or_try<F>(self, f: F) -> Result<U, EnumExtractValueError<T>>
+    where
+        F: FnOnce(T) -> Result<U, EnumExtractValueError<T>>,
+    {
+        match self {
+            Ok(value) => Ok(value),
+            Err(first) => f(first.value).map_err(|second| {
+                EnumExtractValueError::from_plain_error(
+                    first.source.or(second.source),
+                    second.value,
+                )
+            }),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{EnumExtractError, EnumExtractValueError, PathSegment};
+
+/// Extension methods for adding context to the errors of nested extractions.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::{EnumExtractError, ResultExt};
+///
+/// fn extract_item() -> Result<u32, EnumExtractError> {
+///     // normally returned by a function generated with `#[derive(EnumExtract)]`
+///     Err(EnumExtractError::new("Object", "Array"))
+/// }
+///
+/// let error = extract_item()
+///     .context(3)
+///     .with_path(["body", "items"])
+///     .unwrap_err();
+/// assert_eq!(error.to_string(), "at body.items[3]: expected Object, got Array");
+/// ```
+pub trait ResultExt {
+    /// Adds a segment, such as a field name or an index, in front of the error's path.
+    fn context(self, segment: impl Into<PathSegment>) -> Self;
+
+    /// Adds several segments in front of the error's path, from the outermost value inwards.
+    fn with_path<I>(self, path: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathSegment>;
+}
+
+impl<U> ResultExt for Result<U, EnumExtractError> {
+    fn context(self, segment: impl Into<PathSegment>) -> Self {
+        self.map_err(|error| error.context(segment))
+    }
+
+    fn with_path<I>(self, path: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathSegment>,
+    {
+        self.map_err(|error| error.with_path(path))
+    }
+}
+
+impl<U, T> ResultExt for Result<U, EnumExtractValueError<T>> {
+    fn context(self, segment: impl Into<PathSegment>) -> Self {
+        self.map_err(|error| error.context(segment))
+    }
+
+    fn with_path<I>(self, path: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathSegment>,
+    {
+        self.map_err(|error| error.with_path(path))
+    }
+}
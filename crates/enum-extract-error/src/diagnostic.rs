@@ -0,0 +1,65 @@
+use std::fmt;
+
+use miette::Diagnostic;
+
+use crate::{EnumExtractError, EnumExtractValueError, ErrorKind, ExtractErrors};
+
+/// Reports an [`EnumExtractError`] with an error code for its kind and a help line listing the valid variants.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::EnumExtractError;
+/// use miette::Diagnostic;
+///
+/// let error = EnumExtractError::new_one_of(["File", "Url"], "Ssh");
+/// assert_eq!(error.code().unwrap().to_string(), "enum_extract::wrong_variant_of");
+/// assert_eq!(error.help().unwrap().to_string(), "valid variants: File, Url");
+/// ```
+impl Diagnostic for EnumExtractError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let code = match self.root_kind() {
+            ErrorKind::WrongVariant => "enum_extract::wrong_variant",
+            ErrorKind::WrongVariantOf => "enum_extract::wrong_variant_of",
+            ErrorKind::MissingKey => "enum_extract::missing_key",
+            ErrorKind::Nested => "enum_extract::nested",
+        };
+
+        Some(Box::new(code))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let names = self.expected().names();
+        let help = match (self.root_kind(), names) {
+            (ErrorKind::MissingKey, _) => return None,
+            (_, [name]) => format!("valid variant: {}", name),
+            _ => format!("valid variants: {}", names.join(", ")),
+        };
+
+        Some(Box::new(help))
+    }
+}
+
+/// Reports the inner [`EnumExtractError`].
+impl<T> Diagnostic for EnumExtractValueError<T> {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.source.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.source.help()
+    }
+}
+
+/// Reports every collected error as a related diagnostic.
+impl Diagnostic for ExtractErrors {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("enum_extract::errors"))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        Some(Box::new(
+            self.errors().iter().map(|error| error as &dyn Diagnostic),
+        ))
+    }
+}
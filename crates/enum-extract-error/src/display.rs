@@ -0,0 +1,64 @@
+use std::{borrow::Cow, fmt};
+
+/// User-facing wording for an error, which replaces the variant names in its `Display` output,
+/// and the `Debug` output of the actual value.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct CustomDisplay {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub expected: Option<Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub actual: Option<Cow<'static, str>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Cow<'static, str>>,
+    /// The path of the variants that hold the enum, ex: `Outer::Inner`,
+    /// which is put in front of the variant names in the `Display` output.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub qualifier: Option<Cow<'static, str>>,
+    /// The `Debug` output of the actual value.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub actual_debug: Option<String>,
+}
+
+/// Writes a message template, replacing `{expected}` and `{actual}` with the given names.
+pub(crate) fn write_template(
+    f: &mut fmt::Formatter<'_>,
+    template: &str,
+    expected: &str,
+    actual: &str,
+) -> fmt::Result {
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        f.write_str(&rest[..start])?;
+        rest = &rest[start..];
+
+        if let Some(after) = rest.strip_prefix("{expected}") {
+            f.write_str(expected)?;
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("{actual}") {
+            f.write_str(actual)?;
+            rest = after;
+        } else {
+            f.write_str("{")?;
+            rest = &rest[1..];
+        }
+    }
+
+    f.write_str(rest)
+}
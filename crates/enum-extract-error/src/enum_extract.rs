@@ -0,0 +1,95 @@
+use std::{fmt, hash};
+
+use crate::{EnumExtractError, VariantName};
+
+/// An enum that derives `EnumExtract`, for code that is generic over derived enums.
+///
+/// The derive generates a fieldless `[Enum]Kind` enum with one variant for each variant of the enum,
+/// which is used to compare variants without comparing their fields.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::{EnumExtract, EnumExtractError, VariantName};
+///
+/// fn expect_kind<E: EnumExtract>(value: &E, expected: E::Kind) -> Result<(), EnumExtractError> {
+///     if value.kind() == expected {
+///         Ok(())
+///     } else {
+///         Err(value.mismatch(expected))
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// enum NumberKind {
+///     One,
+///     Two,
+/// }
+///
+/// enum Number {
+///     One,
+///     Two(u32),
+/// }
+///
+/// // normally implemented by `#[derive(EnumExtract)]`
+/// impl VariantName for NumberKind {
+///     const VARIANT_NAMES: &'static [&'static str] = &["One", "Two"];
+///
+///     fn variant_name(&self) -> &'static str {
+///         Self::VARIANT_NAMES[*self as usize]
+///     }
+/// }
+///
+/// impl VariantName for Number {
+///     const VARIANT_NAMES: &'static [&'static str] = &["One", "Two"];
+///
+///     fn variant_name(&self) -> &'static str {
+///         self.kind().variant_name()
+///     }
+/// }
+///
+/// impl EnumExtract for Number {
+///     type Kind = NumberKind;
+///
+///     fn kind(&self) -> NumberKind {
+///         match self {
+///             Number::One => NumberKind::One,
+///             Number::Two(_) => NumberKind::Two,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Number::VARIANT_COUNT, 2);
+/// assert!(expect_kind(&Number::Two(2), NumberKind::Two).is_ok());
+/// assert_eq!(
+///     expect_kind(&Number::Two(2), NumberKind::One).unwrap_err().to_string(),
+///     "expected One, got Two"
+/// );
+/// ```
+pub trait EnumExtract: VariantName {
+    /// The fieldless enum with one variant for each variant of this enum, ex: `ShapeKind` for `Shape`.
+    type Kind: VariantName + fmt::Debug + Clone + Copy + PartialEq + Eq + hash::Hash + 'static;
+
+    /// The number of variants.
+    const VARIANT_COUNT: usize = Self::VARIANT_NAMES.len();
+
+    /// Returns the kind of the variant.
+    fn kind(&self) -> Self::Kind;
+
+    /// Returns the error for a value that was expected to be of the `expected` kind.
+    ///
+    /// The error uses the user-facing names of the variants when they have one.
+    /// The derived implementation also uses the messages set with `#[extract(message = "...")]`,
+    /// so its errors read the same as those of the enum's own functions.
+    #[track_caller]
+    fn mismatch(&self, expected: Self::Kind) -> EnumExtractError {
+        let error = EnumExtractError::new(expected.variant_name(), self.variant_name());
+        let expected_display = expected.variant_display_name();
+        let actual_display = self.variant_display_name();
+        if expected_display == expected.variant_name() && actual_display == self.variant_name() {
+            return error;
+        }
+
+        error.with_display(expected_display, actual_display)
+    }
+}
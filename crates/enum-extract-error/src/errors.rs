@@ -0,0 +1,190 @@
+use std::fmt;
+
+use crate::EnumExtractError;
+
+/// A collection of extraction errors, used to report every failed extraction at once
+/// instead of stopping at the first one.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::{EnumExtractError, ExtractErrors, ResultExt};
+///
+/// let mut errors = ExtractErrors::new();
+///
+/// let name: Result<&str, EnumExtractError> = Ok("name");
+/// let port: Result<u16, EnumExtractError> = Err(EnumExtractError::new("Integer", "String"));
+/// let host: Result<&str, EnumExtractError> = Err(EnumExtractError::new("String", "Null"));
+///
+/// assert_eq!(errors.check(name), Some("name"));
+/// assert_eq!(errors.check(port.context("port")), None);
+/// assert_eq!(errors.check(host.context("host")), None);
+///
+/// let report = errors.into_result().unwrap_err();
+/// assert_eq!(
+///     report.to_string(),
+///     "2 extraction errors:\n  at port: expected Integer, got String\n  at host: expected String, got Null"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ExtractErrors {
+    errors: Vec<EnumExtractError>,
+}
+
+impl ExtractErrors {
+    /// Create a new, empty [`ExtractErrors`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an error.
+    pub fn push(&mut self, error: impl Into<EnumExtractError>) {
+        self.errors.push(error.into());
+    }
+
+    /// Returns the value of a successful extraction, or records the error and returns `None`.
+    pub fn check<T, E>(&mut self, result: Result<T, E>) -> Option<T>
+    where
+        E: Into<EnumExtractError>,
+    {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    /// Returns the recorded errors, in the order they were recorded.
+    pub fn errors(&self) -> &[EnumExtractError] {
+        &self.errors
+    }
+
+    /// Returns the number of recorded errors.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if no errors were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns `Ok` if no errors were recorded, or all of the errors otherwise.
+    pub fn into_result(self) -> Result<(), ExtractErrors> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl fmt::Display for ExtractErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.errors.len() {
+            1 => write!(f, "1 extraction error:")?,
+            len => write!(f, "{} extraction errors:", len)?,
+        }
+
+        for error in &self.errors {
+            write!(f, "\n  {}", error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ExtractErrors {}
+
+impl From<EnumExtractError> for ExtractErrors {
+    fn from(error: EnumExtractError) -> Self {
+        Self {
+            errors: vec![error],
+        }
+    }
+}
+
+impl Extend<EnumExtractError> for ExtractErrors {
+    fn extend<I: IntoIterator<Item = EnumExtractError>>(&mut self, iter: I) {
+        self.errors.extend(iter);
+    }
+}
+
+impl FromIterator<EnumExtractError> for ExtractErrors {
+    fn from_iter<I: IntoIterator<Item = EnumExtractError>>(iter: I) -> Self {
+        Self {
+            errors: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for ExtractErrors {
+    type Item = EnumExtractError;
+    type IntoIter = std::vec::IntoIter<EnumExtractError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ExtractErrors {
+    type Item = &'a EnumExtractError;
+    type IntoIter = std::slice::Iter<'a, EnumExtractError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+/// Runs several extractions and returns all of the values, or every error if any of them failed.
+///
+/// Each argument is a `Result` whose error converts into [`EnumExtractError`],
+/// and the values are returned as a tuple in the same order.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::{try_all, EnumExtractError};
+///
+/// let name: Result<&str, EnumExtractError> = Ok("name");
+/// let port: Result<u16, EnumExtractError> = Ok(80);
+///
+/// let (name, port) = try_all!(name, port).unwrap();
+/// assert_eq!(name, "name");
+/// assert_eq!(port, 80);
+///
+/// let name: Result<&str, EnumExtractError> = Err(EnumExtractError::new("String", "Null"));
+/// let port: Result<u16, EnumExtractError> = Err(EnumExtractError::new("Integer", "Null"));
+///
+/// let errors = try_all!(name, port).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// ```
+#[macro_export]
+macro_rules! try_all {
+    ($($result:expr),+ $(,)?) => {{
+        let mut errors = $crate::ExtractErrors::new();
+        $crate::__try_all!(errors; []; $($result,)+)
+    }};
+}
+
+/// Checks each result in turn, binding each value to a new hygienic `value` identifier.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __try_all {
+    ($errors:ident; [$($value:ident)*]; $result:expr, $($rest:expr,)*) => {{
+        let value = $errors.check($result);
+        $crate::__try_all!($errors; [$($value)* value]; $($rest,)*)
+    }};
+    ($errors:ident; [$($value:ident)*];) => {
+        match $errors.into_result() {
+            ::core::result::Result::Ok(()) => {
+                ::core::result::Result::Ok(($(::core::option::Option::unwrap($value),)*))
+            }
+            ::core::result::Result::Err(errors) => ::core::result::Result::Err(errors),
+        }
+    };
+}
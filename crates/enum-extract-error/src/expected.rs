@@ -0,0 +1,115 @@
+use std::{borrow::Cow, fmt};
+
+/// The variant, or variants, that an extraction expected to find.
+///
+/// Most errors expect a single variant.
+/// Errors produced by chaining several extractions with
+/// [`ValueResultExt::or_try`](crate::ValueResultExt::or_try) expect any one of the attempted variants,
+/// and errors produced by group functions such as `as_[group]` expect one of the variants in the group.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::Expected;
+///
+/// let expected = Expected::Variant("One".into()).or(Expected::Variant("Two".into()));
+/// assert_eq!(expected.names(), &["One", "Two"]);
+/// assert_eq!(expected.to_string(), "One or Two");
+/// assert_eq!(expected, "One or Two");
+/// ```
+///
+/// More kinds of expectations may be added, so matching on it requires a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Expected {
+    /// A single variant.
+    Variant(Cow<'static, str>),
+    /// Any one of several variants.
+    AnyOf {
+        /// The names of the variants, in the order they were attempted or declared.
+        names: Vec<Cow<'static, str>>,
+        /// Only changes how the variants are rendered, not which ones are expected.
+        ///
+        /// `true` for the members of a group, from group functions such as `as_[group]`,
+        /// which are rendered as `one of [A, B]`.
+        /// `false` for the variants of chained extractions, from [`Expected::or`]
+        /// or [`ValueResultExt::or_try`](crate::ValueResultExt::or_try), which are rendered as `A or B`.
+        group: bool,
+    },
+}
+
+impl Expected {
+    /// Returns the names of all of the expected variants.
+    pub fn names(&self) -> &[Cow<'static, str>] {
+        match self {
+            Expected::Variant(name) => std::slice::from_ref(name),
+            Expected::AnyOf { names, .. } => names,
+        }
+    }
+
+    /// Applies `f` to the name of every expected variant.
+    pub(crate) fn map_names(
+        self,
+        mut f: impl FnMut(Cow<'static, str>) -> Cow<'static, str>,
+    ) -> Self {
+        match self {
+            Expected::Variant(name) => Expected::Variant(f(name)),
+            Expected::AnyOf { names, group } => Expected::AnyOf {
+                names: names.into_iter().map(f).collect(),
+                group,
+            },
+        }
+    }
+
+    /// Combines two expectations into one that accepts the variants of both.
+    pub fn or(self, other: Expected) -> Expected {
+        let mut names = match self {
+            Expected::Variant(name) => vec![name],
+            Expected::AnyOf { names, .. } => names,
+        };
+        names.extend_from_slice(other.names());
+
+        Expected::AnyOf {
+            names,
+            group: false,
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Variant(name) => f.write_str(name),
+            Expected::AnyOf { names, group: true } => write!(f, "one of [{}]", names.join(", ")),
+            Expected::AnyOf {
+                names,
+                group: false,
+            } => f.write_str(&names.join(" or ")),
+        }
+    }
+}
+
+/// Compares the expectation with its rendered form, as produced by [`Display`](fmt::Display).
+impl PartialEq<str> for Expected {
+    fn eq(&self, other: &str) -> bool {
+        let mut remaining = Remaining(other);
+        fmt::write(&mut remaining, format_args!("{}", self)).is_ok() && remaining.0.is_empty()
+    }
+}
+
+impl PartialEq<&str> for Expected {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// A [`fmt::Write`] that consumes a string, failing as soon as the output stops matching it.
+struct Remaining<'a>(&'a str);
+
+impl fmt::Write for Remaining<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
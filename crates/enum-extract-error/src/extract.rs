@@ -0,0 +1,93 @@
+use crate::{EnumExtractError, EnumExtractValueError};
+
+/// Extracts the `V` variant of an enum, implemented by `#[derive(EnumExtract)]`.
+///
+/// `V` is a zero-sized marker type, which the derive generates for each variant in a module
+/// named after the enum when it has `#[extract(markers)]`, ex: `shape::Circle` for `Shape::Circle`.
+/// This lets generic code extract a variant without knowing the name of its `as_[variant]` function.
+///
+/// The functions are usually called through [`ExtractExt`], which takes the marker type as a generic argument.
+pub trait Extract<V>: Sized {
+    /// The type returned by [`Extract::as_variant`], ex: `&'a f64`.
+    type Ref<'a>
+    where
+        Self: 'a;
+
+    /// The type returned by [`Extract::as_variant_mut`], ex: `&'a mut f64`.
+    type Mut<'a>
+    where
+        Self: 'a;
+
+    /// The type returned by [`Extract::into_variant`], ex: `f64`.
+    type Owned;
+
+    /// Returns whether the value is the `V` variant.
+    #[track_caller]
+    fn is_variant(&self) -> bool;
+
+    /// Returns references to the fields of the `V` variant.
+    #[track_caller]
+    fn as_variant(&self) -> Result<Self::Ref<'_>, EnumExtractError>;
+
+    /// Returns mutable references to the fields of the `V` variant.
+    #[track_caller]
+    fn as_variant_mut(&mut self) -> Result<Self::Mut<'_>, EnumExtractError>;
+
+    /// Returns the fields of the `V` variant.
+    #[track_caller]
+    fn into_variant(self) -> Result<Self::Owned, EnumExtractValueError<Self>>;
+}
+
+/// Extension methods for extracting a variant by its marker type, implemented for every type.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::{EnumExtractError, Extract, ExtractExt};
+///
+/// fn require<V, E>(value: &E) -> Result<E::Ref<'_>, EnumExtractError>
+/// where
+///     E: Extract<V>,
+/// {
+///     value.extract_ref::<V>()
+/// }
+/// ```
+pub trait ExtractExt {
+    /// Returns whether the value is the `V` variant.
+    #[track_caller]
+    fn is<V>(&self) -> bool
+    where
+        Self: Extract<V>,
+    {
+        Extract::<V>::is_variant(self)
+    }
+
+    /// Returns references to the fields of the `V` variant.
+    #[track_caller]
+    fn extract_ref<V>(&self) -> Result<<Self as Extract<V>>::Ref<'_>, EnumExtractError>
+    where
+        Self: Extract<V>,
+    {
+        Extract::<V>::as_variant(self)
+    }
+
+    /// Returns mutable references to the fields of the `V` variant.
+    #[track_caller]
+    fn extract_mut<V>(&mut self) -> Result<<Self as Extract<V>>::Mut<'_>, EnumExtractError>
+    where
+        Self: Extract<V>,
+    {
+        Extract::<V>::as_variant_mut(self)
+    }
+
+    /// Returns the fields of the `V` variant.
+    #[track_caller]
+    fn extract_owned<V>(self) -> Result<<Self as Extract<V>>::Owned, EnumExtractValueError<Self>>
+    where
+        Self: Extract<V>,
+    {
+        Extract::<V>::into_variant(self)
+    }
+}
+
+impl<T> ExtractExt for T {}
@@ -0,0 +1,50 @@
+use std::sync::{PoisonError, RwLock};
+
+use crate::EnumExtractError;
+
+/// A function that is called with every [`EnumExtractError`] when it is created.
+pub type MismatchHook = fn(&EnumExtractError);
+
+static HOOK: RwLock<Option<MismatchHook>> = RwLock::new(None);
+
+/// Sets the function that is called with every [`EnumExtractError`] when it is created,
+/// such as by a failed `as_[variant]` or `into_[variant]` function,
+/// replacing the previous one.
+///
+/// The hook sees the error as it was created, before any path or custom wording is added.
+/// Errors that are deserialized, or combined with [`EnumExtractError::or`], do not call the hook.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use enum_extract_error::{set_mismatch_hook, EnumExtractError};
+///
+/// static MISMATCHES: AtomicUsize = AtomicUsize::new(0);
+///
+/// set_mismatch_hook(|error| {
+///     assert_eq!(error.actual(), "Three");
+///     MISMATCHES.fetch_add(1, Ordering::Relaxed);
+/// });
+///
+/// let _ = EnumExtractError::new("One", "Three");
+/// assert_eq!(MISMATCHES.load(Ordering::Relaxed), 1);
+/// ```
+pub fn set_mismatch_hook(hook: MismatchHook) {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(hook);
+}
+
+/// Removes the function set with [`set_mismatch_hook`], and returns it.
+pub fn take_mismatch_hook() -> Option<MismatchHook> {
+    HOOK.write().unwrap_or_else(PoisonError::into_inner).take()
+}
+
+/// Calls the hook, if one is set, with a newly created error.
+pub(crate) fn call_mismatch_hook(error: &EnumExtractError) {
+    // the lock is released before the call, so the hook may create errors itself
+    let hook = *HOOK.read().unwrap_or_else(PoisonError::into_inner);
+    if let Some(hook) = hook {
+        hook(error);
+    }
+}
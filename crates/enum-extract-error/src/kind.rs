@@ -0,0 +1,29 @@
+/// The kind of failure described by an [`EnumExtractError`](crate::EnumExtractError).
+///
+/// More kinds may be added in the future, so matches must include a wildcard arm.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::{EnumExtractError, ErrorKind};
+///
+/// let error = EnumExtractError::new("One", "Two");
+/// assert_eq!(error.kind(), ErrorKind::WrongVariant);
+///
+/// let error = error.context("number");
+/// assert_eq!(error.kind(), ErrorKind::Nested);
+/// assert_eq!(error.root_kind(), ErrorKind::WrongVariant);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The value was a different variant than the one expected.
+    WrongVariant,
+    /// The value was none of several expected variants.
+    WrongVariantOf,
+    /// A key was missing from a map.
+    MissingKey,
+    /// A nested value failed to extract, see [`EnumExtractError::path`](crate::EnumExtractError::path).
+    Nested,
+}
@@ -12,25 +12,499 @@
 //! let error: EnumExtractError = EnumExtractError::new("One", "Three");
 //! assert_eq!(error.to_string(), "expected One, got Three");
 //! ```
+//!
+//! # Chained Extraction
+//!
+//! Several extractions can be attempted in turn with [`ValueResultExt::or_try`].
+//! If all of them fail, the error lists every variant that was attempted:
+//!
+//! ```rust
+//! use enum_extract_error::EnumExtractError;
+//!
+//! let error = EnumExtractError::new("One", "Three").or(EnumExtractError::new("Two", "Three"));
+//! assert_eq!(error.to_string(), "expected One or Two, got Three");
+//! ```
+//!
+//! # Nested Extraction
+//!
+//! When extracting through several levels of nested values,
+//! [`EnumExtractError::context`] and [`ResultExt`] record where the extraction failed:
+//!
+//! ```rust
+//! use enum_extract_error::EnumExtractError;
+//!
+//! let error = EnumExtractError::new("Object", "Array").context(3).context("items");
+//! assert_eq!(error.to_string(), "at items[3]: expected Object, got Array");
+//! ```
+//!
+//! # Collecting Errors
+//!
+//! [`ExtractErrors`] and [`try_all!`] report every failed extraction at once instead of stopping at the first one:
+//!
+//! ```rust
+//! use enum_extract_error::{try_all, EnumExtractError};
+//!
+//! let name: Result<&str, EnumExtractError> = Err(EnumExtractError::new("String", "Null"));
+//! let port: Result<u16, EnumExtractError> = Ok(80);
+//!
+//! let errors = try_all!(name, port).unwrap_err();
+//! assert_eq!(errors.to_string(), "1 extraction error:\n  expected String, got Null");
+//! ```
+//!
+//! # Features
+//!
+//! - `location`: Records the location of the code that attempted the extraction,
+//!   available from [`EnumExtractError::location`] and printed by the alternate `{:#}` format.
+//! - `backtrace`: Captures a [`Backtrace`](std::backtrace::Backtrace) when an error is created,
+//!   available from [`EnumExtractError::backtrace`] and [`EnumExtractValueError::backtrace`].
+//! - `serde`: Implements `Serialize` and `Deserialize` for the error types.
+//!   The location and backtrace are not serialized.
+//! - `hook`: Calls a function set with [`set_mismatch_hook`] whenever an error is created,
+//!   such as to count mismatches in metrics or tracing.
+//! - `alloc`: Records the `Debug` output of the actual value, available from [`EnumExtractError::actual_debug`].
+//!   Fields marked with `#[extract(redact)]` are rendered as `<redacted>`.
+//! - `miette`: Implements `miette::Diagnostic` for the error types,
+//!   with an error code for each [`ErrorKind`] and a help line listing the valid variants.
+//! - `anyhow`: Adds [`AnyhowResultExt`] for converting results into `anyhow::Result` with context.
+//!
+//! The error types also convert into [`std::io::Error`] of kind [`std::io::ErrorKind::InvalidData`].
 
 #![warn(missing_docs)]
 
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+#[cfg(feature = "anyhow")]
+mod anyhow_ext;
+mod chain;
+mod context;
+#[cfg(feature = "miette")]
+mod diagnostic;
+mod display;
+mod enum_extract;
+mod errors;
+mod expected;
+mod extract;
+#[cfg(feature = "hook")]
+mod hook;
+mod kind;
+mod optics;
+mod path;
+mod variant_name;
+
+#[cfg(feature = "anyhow")]
+pub use anyhow_ext::AnyhowResultExt;
+pub use chain::ValueResultExt;
+pub use context::ResultExt;
+pub use enum_extract::EnumExtract;
+pub use errors::ExtractErrors;
+pub use expected::Expected;
+pub use extract::{Extract, ExtractExt};
+#[cfg(feature = "hook")]
+pub use hook::{set_mismatch_hook, take_mismatch_hook, MismatchHook};
+pub use kind::ErrorKind;
+pub use optics::{FieldLens, Optic, Prism, Then, VariantPrism};
+pub use path::{ErrorPath, PathSegment};
+pub use variant_name::VariantName;
+
+use std::{borrow::Cow, fmt, hash, io, panic::Location};
+
+use display::CustomDisplay;
 use thiserror::Error;
 
 /// An error that occurs when the actual variant does not match the expected variant.
+///
+/// See [`EnumExtractError::kind`] for the different kinds of failure.
+///
+/// Errors are equal if they describe the same failure,
+/// regardless of their wording, where they were created or the backtrace they captured.
 #[derive(Error, Debug, Clone)]
-#[error("expected {expected}, got {actual}")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumExtractError {
-    /// The name of the expected variant.
-    pub expected: &'static str,
+    /// The name of the expected variant, or variants.
+    ///
+    /// For a [`ErrorKind::MissingKey`] error, this is the name of the missing key.
+    pub expected: Expected,
     /// The name of the actual variant.
-    pub actual: &'static str,
+    ///
+    /// For a [`ErrorKind::MissingKey`] error, this is empty.
+    pub actual: Cow<'static, str>,
+    kind: ErrorKind,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "ErrorPath::is_empty")
+    )]
+    path: ErrorPath,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    // boxed, since most errors have no custom wording or debug output and the error is returned in every `Result`
+    display: Option<Box<CustomDisplay>>,
+    // a deserialized error is located where it was deserialized
+    #[cfg(feature = "location")]
+    #[cfg_attr(feature = "serde", serde(skip, default = "Location::caller"))]
+    location: &'static Location<'static>,
+    // `Backtrace` is not `Clone`, so it is shared between clones of the error
+    #[cfg(feature = "backtrace")]
+    #[cfg_attr(feature = "serde", serde(skip, default = "disabled_backtrace"))]
+    backtrace: std::sync::Arc<std::backtrace::Backtrace>,
+}
+
+#[cfg(all(feature = "serde", feature = "backtrace"))]
+fn disabled_backtrace() -> std::sync::Arc<std::backtrace::Backtrace> {
+    std::sync::Arc::new(std::backtrace::Backtrace::disabled())
+}
+
+impl PartialEq for EnumExtractError {
+    fn eq(&self, other: &Self) -> bool {
+        self.expected == other.expected
+            && self.actual == other.actual
+            && self.kind == other.kind
+            && self.path == other.path
+    }
+}
+
+impl Eq for EnumExtractError {}
+
+impl hash::Hash for EnumExtractError {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.expected.hash(state);
+        self.actual.hash(state);
+        self.kind.hash(state);
+        self.path.hash(state);
+    }
+}
+
+impl fmt::Display for EnumExtractError {
+    /// Formats the error, and with `{:#}` also the location it was created at if the `location` feature is enabled.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "at {}: ", self.path)?;
+        }
+
+        match (self.kind, self.message()) {
+            (ErrorKind::MissingKey, _) => write!(f, "missing key {}", self.expected)?,
+            (_, Some(message)) => display::write_template(
+                f,
+                message,
+                &self.expected_display(),
+                &self.actual_display(),
+            )?,
+            _ => write!(
+                f,
+                "expected {}, got {}",
+                self.expected_display(),
+                self.actual_display()
+            )?,
+        }
+
+        if let (true, Some(location)) = (f.alternate(), self.location()) {
+            write!(f, " (at {})", location)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl EnumExtractError {
     /// Create a new [`EnumExtractError`].
-    pub fn new(expected: &'static str, actual: &'static str) -> Self {
-        Self { expected, actual }
+    #[track_caller]
+    pub fn new(
+        expected: impl Into<Cow<'static, str>>,
+        actual: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self::with_kind(
+            Expected::Variant(expected.into()),
+            actual.into(),
+            ErrorKind::WrongVariant,
+        )
+    }
+
+    /// Create a new [`EnumExtractError`] that expected one of several variants.
+    #[track_caller]
+    pub fn new_one_of(
+        expected: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+        actual: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self::with_kind(
+            Expected::AnyOf {
+                names: expected.into_iter().map(Into::into).collect(),
+                group: true,
+            },
+            actual.into(),
+            ErrorKind::WrongVariantOf,
+        )
+    }
+
+    /// Create a new [`EnumExtractError`] for a key that was missing from a map.
+    #[track_caller]
+    pub fn new_missing_key(key: impl Into<Cow<'static, str>>) -> Self {
+        Self::with_kind(
+            Expected::Variant(key.into()),
+            Cow::Borrowed(""),
+            ErrorKind::MissingKey,
+        )
+    }
+
+    #[track_caller]
+    fn with_kind(expected: Expected, actual: Cow<'static, str>, kind: ErrorKind) -> Self {
+        let error = Self {
+            expected,
+            actual,
+            kind,
+            path: ErrorPath::default(),
+            display: None,
+            #[cfg(feature = "location")]
+            location: Location::caller(),
+            #[cfg(feature = "backtrace")]
+            backtrace: std::sync::Arc::new(std::backtrace::Backtrace::capture()),
+        };
+
+        #[cfg(feature = "hook")]
+        hook::call_mismatch_hook(&error);
+
+        error
+    }
+
+    /// Combines two errors for the same value into one that expects the variants of both.
+    ///
+    /// The path of `other` is kept, since both errors describe the same value,
+    /// and the location of `self` is kept, since it is where the first extraction was attempted.
+    /// Custom wording set with [`EnumExtractError::with_display`] or [`EnumExtractError::with_message`] is dropped,
+    /// and the `Debug` output of the actual value is taken from `other`.
+    pub fn or(self, other: EnumExtractError) -> Self {
+        let actual_debug = other.actual_debug().map(str::to_string);
+        let error = Self {
+            expected: self.expected.or(other.expected),
+            actual: other.actual,
+            kind: ErrorKind::WrongVariantOf,
+            path: other.path,
+            display: None,
+            #[cfg(feature = "location")]
+            location: self.location,
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
+        };
+
+        error.__with_actual_debug(|| actual_debug)
+    }
+
+    /// Returns the location of the code that attempted the extraction.
+    ///
+    /// Always returns `None` unless the `location` feature is enabled.
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        #[cfg(feature = "location")]
+        return Some(self.location);
+
+        #[cfg(not(feature = "location"))]
+        return None;
+    }
+
+    /// Returns the backtrace captured when the error was created.
+    ///
+    /// Always returns `None` unless the `backtrace` feature is enabled.
+    /// Like [`Backtrace::capture`](std::backtrace::Backtrace::capture), the backtrace is only
+    /// captured if the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables are set.
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        #[cfg(feature = "backtrace")]
+        return Some(&self.backtrace);
+
+        #[cfg(not(feature = "backtrace"))]
+        return None;
+    }
+
+    /// Returns the `Debug` output of the actual value, captured by the generated functions if the enum implements `Debug`.
+    ///
+    /// Always returns `None` unless the `alloc` feature is enabled.
+    /// Fields marked with `#[extract(redact)]` are rendered as `<redacted>`.
+    pub fn actual_debug(&self) -> Option<&str> {
+        #[cfg(feature = "alloc")]
+        return self.display.as_ref()?.actual_debug.as_deref();
+
+        #[cfg(not(feature = "alloc"))]
+        return None;
+    }
+
+    /// Sets the `Debug` output of the actual value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use enum_extract_error::EnumExtractError;
+    ///
+    /// let error = EnumExtractError::new("One", "Two").with_actual_debug("Two(2)");
+    /// assert_eq!(error.actual_debug(), Some("Two(2)"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_actual_debug(mut self, actual_debug: impl Into<String>) -> Self {
+        self.display.get_or_insert_with(Box::default).actual_debug = Some(actual_debug.into());
+        self
+    }
+
+    /// Sets the `Debug` output of the actual value from `capture`, which is only called if the `alloc` feature is enabled.
+    #[doc(hidden)]
+    #[allow(unused_mut, unused_variables)]
+    pub fn __with_actual_debug(mut self, capture: impl FnOnce() -> Option<String>) -> Self {
+        #[cfg(feature = "alloc")]
+        if let Some(actual_debug) = capture() {
+            self = self.with_actual_debug(actual_debug);
+        }
+
+        self
+    }
+
+    /// Returns the kind of failure.
+    ///
+    /// Errors with a path are [`ErrorKind::Nested`], use [`EnumExtractError::root_kind`] for the kind of the innermost failure.
+    pub fn kind(&self) -> ErrorKind {
+        if self.path.is_empty() {
+            self.kind
+        } else {
+            ErrorKind::Nested
+        }
+    }
+
+    /// Returns the kind of the innermost failure, ignoring the path.
+    pub fn root_kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the name of the expected variant, or variants.
+    pub fn expected(&self) -> &Expected {
+        &self.expected
+    }
+
+    /// Returns the name of the actual variant.
+    pub fn actual(&self) -> &str {
+        &self.actual
+    }
+
+    /// Sets user-facing names for the expected and actual variants, which are used in place of the variant names when the error is displayed.
+    ///
+    /// The variant names are still available from [`EnumExtractError::expected`] and [`EnumExtractError::actual`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use enum_extract_error::EnumExtractError;
+    ///
+    /// let error = EnumExtractError::new("File", "Url").with_display("a file source", "a URL source");
+    /// assert_eq!(error.to_string(), "expected a file source, got a URL source");
+    /// assert_eq!(error.actual(), "Url");
+    /// ```
+    pub fn with_display(
+        mut self,
+        expected: impl Into<Cow<'static, str>>,
+        actual: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let display = self.display.get_or_insert_with(Box::default);
+        display.expected = Some(expected.into());
+        display.actual = Some(actual.into());
+        self
+    }
+
+    /// Sets a message template that replaces the `expected {expected}, got {actual}` wording when the error is displayed.
+    ///
+    /// `{expected}` and `{actual}` in the template are replaced with the user-facing names of the variants.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use enum_extract_error::EnumExtractError;
+    ///
+    /// let error = EnumExtractError::new("File", "Url")
+    ///     .with_display("a file source", "a URL source")
+    ///     .with_message("this command requires {expected}, but {actual} was given");
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "this command requires a file source, but a URL source was given"
+    /// );
+    /// ```
+    pub fn with_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.display.get_or_insert_with(Box::default).message = Some(message.into());
+        self
+    }
+
+    /// Returns the message template set with [`EnumExtractError::with_message`].
+    pub fn message(&self) -> Option<&str> {
+        self.display.as_ref()?.message.as_deref()
+    }
+
+    /// Returns the user-facing name of the expected variant, or variants,
+    /// which defaults to the variant names, qualified by [`EnumExtractError::within`].
+    pub fn expected_display(&self) -> Cow<'_, str> {
+        let display = self.display.as_deref();
+        match display.and_then(|display| display.expected.as_ref()) {
+            Some(expected) => Cow::Borrowed(expected),
+            None => match display.and_then(|display| display.qualifier.as_deref()) {
+                Some(qualifier) => Cow::Owned(
+                    self.expected
+                        .clone()
+                        .map_names(|name| Cow::Owned(format!("{}::{}", qualifier, name)))
+                        .to_string(),
+                ),
+                None => Cow::Owned(self.expected.to_string()),
+            },
+        }
+    }
+
+    /// Returns the user-facing name of the actual variant,
+    /// which defaults to the variant name, qualified by [`EnumExtractError::within`].
+    pub fn actual_display(&self) -> Cow<'_, str> {
+        let display = self.display.as_deref();
+        match display.and_then(|display| display.actual.as_ref()) {
+            Some(actual) => Cow::Borrowed(actual),
+            None => match display.and_then(|display| display.qualifier.as_deref()) {
+                Some(qualifier) => Cow::Owned(format!("{}::{}", qualifier, self.actual)),
+                None => Cow::Borrowed(&self.actual),
+            },
+        }
+    }
+
+    /// Returns the path to the value that failed to extract.
+    pub fn path(&self) -> &ErrorPath {
+        &self.path
+    }
+
+    /// Adds a segment, such as a field name or an index, in front of the error's path.
+    pub fn context(mut self, segment: impl Into<PathSegment>) -> Self {
+        self.path.push_front(segment.into());
+        self
+    }
+
+    /// Adds several segments in front of the error's path, from the outermost value inwards.
+    pub fn with_path<I>(mut self, path: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathSegment>,
+    {
+        self.path.extend_front(path.into_iter().map(Into::into));
+        self
+    }
+
+    /// Qualifies the expected and actual variant names in the `Display` output with the outer variant that holds the enum, ex: `Inner::A`.
+    ///
+    /// Calling it again adds another level in front, ex: `Outer::Inner::A`.
+    /// [`EnumExtractError::expected`] and [`EnumExtractError::actual`] keep the plain variant names,
+    /// and user-facing names set with [`EnumExtractError::with_display`] are not changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use enum_extract_error::EnumExtractError;
+    ///
+    /// let error = EnumExtractError::new("A", "B").within("Inner").within("Outer");
+    /// assert_eq!(error.to_string(), "expected Outer::Inner::A, got Outer::Inner::B");
+    /// assert_eq!(error.actual(), "B");
+    /// ```
+    pub fn within(mut self, variant: &str) -> Self {
+        let display = self.display.get_or_insert_with(Box::default);
+        display.qualifier = Some(match display.qualifier.take() {
+            Some(qualifier) => Cow::Owned(format!("{}::{}", variant, qualifier)),
+            None => Cow::Owned(variant.to_string()),
+        });
+        self
     }
 }
 
@@ -38,8 +512,11 @@ impl EnumExtractError {
 ///
 /// This error is only produced by functions that consume the value,
 /// and therefore holds on to the value in case it is needed.
-#[derive(Error, Debug)]
-#[error("{source}")]
+///
+/// The value does not need to implement `Debug`, so the `Debug` output only names its type.
+/// Use [`EnumExtractValueError::debug_with_value`] to include the value itself.
+#[derive(Error, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumExtractValueError<T> {
     /// The inner extraction error.
     #[source]
@@ -59,12 +536,100 @@ impl<T> EnumExtractValueError<T> {
     }
 
     /// Create a new [`EnumExtractError`].
-    pub fn new(expected: &'static str, actual: &'static str, value: T) -> Self {
+    #[track_caller]
+    pub fn new(
+        expected: impl Into<Cow<'static, str>>,
+        actual: impl Into<Cow<'static, str>>,
+        value: T,
+    ) -> Self {
         Self {
             source: EnumExtractError::new(expected, actual),
             value,
         }
     }
+
+    /// Create a new [`EnumExtractValueError`] that expected one of several variants.
+    #[track_caller]
+    pub fn new_one_of(
+        expected: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+        actual: impl Into<Cow<'static, str>>,
+        value: T,
+    ) -> Self {
+        Self {
+            source: EnumExtractError::new_one_of(expected, actual),
+            value,
+        }
+    }
+
+    /// Adds a segment, such as a field name or an index, in front of the error's path.
+    pub fn context(self, segment: impl Into<PathSegment>) -> Self {
+        Self {
+            source: self.source.context(segment),
+            value: self.value,
+        }
+    }
+
+    /// Adds several segments in front of the error's path, from the outermost value inwards.
+    pub fn with_path<I>(self, path: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathSegment>,
+    {
+        Self {
+            source: self.source.with_path(path),
+            value: self.value,
+        }
+    }
+
+    /// Returns the backtrace captured when the error was created, see [`EnumExtractError::backtrace`].
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        self.source.backtrace()
+    }
+
+    /// Returns a wrapper whose `Debug` output includes the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use enum_extract_error::EnumExtractValueError;
+    ///
+    /// let error = EnumExtractValueError::new("One", "Two", 2);
+    /// assert!(format!("{:?}", error).ends_with("value: i32 }"));
+    /// assert!(format!("{:?}", error.debug_with_value()).ends_with("value: 2 }"));
+    /// ```
+    pub fn debug_with_value(&self) -> impl fmt::Debug + '_
+    where
+        T: fmt::Debug,
+    {
+        DebugWithValue(self)
+    }
+}
+
+impl<T> fmt::Display for EnumExtractValueError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.source, f)
+    }
+}
+
+impl<T> fmt::Debug for EnumExtractValueError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnumExtractValueError")
+            .field("source", &self.source)
+            .field("value", &format_args!("{}", std::any::type_name::<T>()))
+            .finish()
+    }
+}
+
+/// Formats an [`EnumExtractValueError`] along with its value.
+struct DebugWithValue<'a, T>(&'a EnumExtractValueError<T>);
+
+impl<T: fmt::Debug> fmt::Debug for DebugWithValue<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnumExtractValueError")
+            .field("source", &self.0.source)
+            .field("value", &self.0.value)
+            .finish()
+    }
 }
 
 impl<T> From<EnumExtractValueError<T>> for EnumExtractError {
@@ -72,3 +637,41 @@ impl<T> From<EnumExtractValueError<T>> for EnumExtractError {
         value.source
     }
 }
+
+/// Converts the error into an [`io::Error`] of kind [`io::ErrorKind::InvalidData`].
+impl From<EnumExtractError> for io::Error {
+    fn from(value: EnumExtractError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, value)
+    }
+}
+
+/// Converts the inner [`EnumExtractError`] into an [`io::Error`] of kind [`io::ErrorKind::InvalidData`],
+/// dropping the value.
+impl<T> From<EnumExtractValueError<T>> for io::Error {
+    fn from(value: EnumExtractValueError<T>) -> Self {
+        value.source.into()
+    }
+}
+
+/// An error that occurs when a variant builder is missing a value for one of its fields.
+///
+/// This error is produced by the `build` function of builders generated with `#[extract(builder)]`.
+#[derive(Error, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[error("missing field {field} for {variant}")]
+pub struct MissingFieldError {
+    /// The name of the variant being built.
+    pub variant: Cow<'static, str>,
+    /// The name of the field that was not set.
+    pub field: Cow<'static, str>,
+}
+
+impl MissingFieldError {
+    /// Create a new [`MissingFieldError`].
+    pub fn new(variant: impl Into<Cow<'static, str>>, field: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            variant: variant.into(),
+            field: field.into(),
+        }
+    }
+}
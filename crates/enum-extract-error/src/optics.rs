@@ -0,0 +1,316 @@
+use std::fmt;
+
+use crate::EnumExtractError;
+
+/// A reusable path into a value, such as a variant of an enum or a field of a struct.
+///
+/// Optics are composed with [`Optic::then`].
+/// When a later step of a composed optic fails, the error's path records the steps before it.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::{FieldLens, Optic, Prism, VariantPrism};
+///
+/// struct Request {
+///     body: Body,
+/// }
+///
+/// enum Body {
+///     Json(String),
+///     Text(String),
+/// }
+///
+/// // normally generated by `#[derive(EnumExtract)]`
+/// const JSON: VariantPrism<Body, String> = VariantPrism::new(
+///     "Json",
+///     |body| match body {
+///         Body::Json(inner) => Ok(inner),
+///         Body::Text(_) => Err(enum_extract_error::EnumExtractError::new("Json", "Text")),
+///     },
+///     |body| match body {
+///         Body::Json(inner) => Ok(inner),
+///         Body::Text(_) => Err(enum_extract_error::EnumExtractError::new("Json", "Text")),
+///     },
+///     |body| match body {
+///         Body::Json(inner) => Ok(inner),
+///         Body::Text(_) => Err(enum_extract_error::EnumExtractError::new("Json", "Text")),
+///     },
+///     Body::Json,
+/// );
+///
+/// const BODY: FieldLens<Request, Body> =
+///     FieldLens::new("body", |request| &request.body, |request| &mut request.body, |request| request.body);
+///
+/// let json = BODY.then(JSON);
+///
+/// let request = Request { body: JSON.review("{}".to_string()) };
+/// assert_eq!(json.preview(&request).unwrap(), "{}");
+///
+/// let request = Request { body: Body::Text("text".to_string()) };
+/// assert_eq!(
+///     json.preview(&request).unwrap_err().to_string(),
+///     "at body: expected Json, got Text"
+/// );
+/// ```
+pub trait Optic {
+    /// The value the optic looks into.
+    type Source;
+    /// The value the optic focuses on.
+    type Focus;
+
+    /// Returns a reference to the focus, or an error if the source does not contain it.
+    fn preview<'a>(&self, source: &'a Self::Source) -> Result<&'a Self::Focus, EnumExtractError>
+    where
+        Self: 'a;
+
+    /// Returns a mutable reference to the focus, or an error if the source does not contain it.
+    fn preview_mut<'a>(
+        &self,
+        source: &'a mut Self::Source,
+    ) -> Result<&'a mut Self::Focus, EnumExtractError>
+    where
+        Self: 'a;
+
+    /// Returns the focus, or an error if the source does not contain it.
+    ///
+    /// The source is dropped on failure, since a composed optic cannot always rebuild it.
+    #[allow(clippy::wrong_self_convention)]
+    fn into_preview(&self, source: Self::Source) -> Result<Self::Focus, EnumExtractError>;
+
+    /// Adds this optic's steps to the path of an error from a later step.
+    fn context(&self, error: EnumExtractError) -> EnumExtractError;
+
+    /// Returns an optic that looks into the focus of this optic with `next`.
+    fn then<O>(self, next: O) -> Then<Self, O>
+    where
+        Self: Sized,
+        O: Optic<Source = Self::Focus>,
+    {
+        Then {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+/// An [`Optic`] that can also build its source from a focus, such as a variant of an enum.
+pub trait Prism: Optic {
+    /// Builds the source from the focus.
+    fn review(&self, focus: Self::Focus) -> Self::Source;
+}
+
+/// A [`Prism`] for a single-field variant of an enum, generated by `#[derive(EnumExtract)]`
+/// as a constant named after the variant, ex: `Shape::CIRCLE` for `Shape::Circle`.
+pub struct VariantPrism<S, F> {
+    name: &'static str,
+    preview: for<'a> fn(&'a S) -> Result<&'a F, EnumExtractError>,
+    preview_mut: for<'a> fn(&'a mut S) -> Result<&'a mut F, EnumExtractError>,
+    into_preview: fn(S) -> Result<F, EnumExtractError>,
+    review: fn(F) -> S,
+}
+
+impl<S, F> VariantPrism<S, F> {
+    /// Create a new [`VariantPrism`] for the variant named `name`.
+    pub const fn new(
+        name: &'static str,
+        preview: for<'a> fn(&'a S) -> Result<&'a F, EnumExtractError>,
+        preview_mut: for<'a> fn(&'a mut S) -> Result<&'a mut F, EnumExtractError>,
+        into_preview: fn(S) -> Result<F, EnumExtractError>,
+        review: fn(F) -> S,
+    ) -> Self {
+        Self {
+            name,
+            preview,
+            preview_mut,
+            into_preview,
+            review,
+        }
+    }
+
+    /// Returns the name of the variant.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<S, F> Clone for VariantPrism<S, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, F> Copy for VariantPrism<S, F> {}
+
+impl<S, F> fmt::Debug for VariantPrism<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VariantPrism").field(&self.name).finish()
+    }
+}
+
+impl<S, F> Optic for VariantPrism<S, F> {
+    type Source = S;
+    type Focus = F;
+
+    fn preview<'a>(&self, source: &'a S) -> Result<&'a F, EnumExtractError>
+    where
+        Self: 'a,
+    {
+        (self.preview)(source)
+    }
+
+    fn preview_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut F, EnumExtractError>
+    where
+        Self: 'a,
+    {
+        (self.preview_mut)(source)
+    }
+
+    fn into_preview(&self, source: S) -> Result<F, EnumExtractError> {
+        (self.into_preview)(source)
+    }
+
+    fn context(&self, error: EnumExtractError) -> EnumExtractError {
+        error.context(self.name)
+    }
+}
+
+impl<S, F> Prism for VariantPrism<S, F> {
+    fn review(&self, focus: F) -> S {
+        (self.review)(focus)
+    }
+}
+
+/// An [`Optic`] for a field of a struct, which always contains its focus.
+///
+/// `#[derive(EnumExtract)]` with `#[extract(lenses)]` on a struct generates one for every field,
+/// as a constant named after the field, ex: `Request::BODY` for `Request::body`.
+pub struct FieldLens<S, F> {
+    name: &'static str,
+    get: for<'a> fn(&'a S) -> &'a F,
+    get_mut: for<'a> fn(&'a mut S) -> &'a mut F,
+    into: fn(S) -> F,
+}
+
+impl<S, F> FieldLens<S, F> {
+    /// Create a new [`FieldLens`] for the field named `name`.
+    pub const fn new(
+        name: &'static str,
+        get: for<'a> fn(&'a S) -> &'a F,
+        get_mut: for<'a> fn(&'a mut S) -> &'a mut F,
+        into: fn(S) -> F,
+    ) -> Self {
+        Self {
+            name,
+            get,
+            get_mut,
+            into,
+        }
+    }
+
+    /// Returns the name of the field.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<S, F> Clone for FieldLens<S, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, F> Copy for FieldLens<S, F> {}
+
+impl<S, F> fmt::Debug for FieldLens<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldLens").field(&self.name).finish()
+    }
+}
+
+impl<S, F> Optic for FieldLens<S, F> {
+    type Source = S;
+    type Focus = F;
+
+    fn preview<'a>(&self, source: &'a S) -> Result<&'a F, EnumExtractError>
+    where
+        Self: 'a,
+    {
+        Ok((self.get)(source))
+    }
+
+    fn preview_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut F, EnumExtractError>
+    where
+        Self: 'a,
+    {
+        Ok((self.get_mut)(source))
+    }
+
+    fn into_preview(&self, source: S) -> Result<F, EnumExtractError> {
+        Ok((self.into)(source))
+    }
+
+    fn context(&self, error: EnumExtractError) -> EnumExtractError {
+        error.context(self.name)
+    }
+}
+
+/// Two optics composed with [`Optic::then`].
+#[derive(Debug, Clone, Copy)]
+pub struct Then<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Optic for Then<A, B>
+where
+    A: Optic,
+    B: Optic<Source = A::Focus>,
+{
+    type Source = A::Source;
+    type Focus = B::Focus;
+
+    fn preview<'a>(&self, source: &'a A::Source) -> Result<&'a B::Focus, EnumExtractError>
+    where
+        Self: 'a,
+    {
+        let inner = self.first.preview(source)?;
+        self.second
+            .preview(inner)
+            .map_err(|error| self.first.context(error))
+    }
+
+    fn preview_mut<'a>(
+        &self,
+        source: &'a mut A::Source,
+    ) -> Result<&'a mut B::Focus, EnumExtractError>
+    where
+        Self: 'a,
+    {
+        let inner = self.first.preview_mut(source)?;
+        self.second
+            .preview_mut(inner)
+            .map_err(|error| self.first.context(error))
+    }
+
+    fn into_preview(&self, source: A::Source) -> Result<B::Focus, EnumExtractError> {
+        let inner = self.first.into_preview(source)?;
+        self.second
+            .into_preview(inner)
+            .map_err(|error| self.first.context(error))
+    }
+
+    fn context(&self, error: EnumExtractError) -> EnumExtractError {
+        self.first.context(self.second.context(error))
+    }
+}
+
+impl<A, B> Prism for Then<A, B>
+where
+    A: Prism,
+    B: Prism<Source = A::Focus>,
+{
+    fn review(&self, focus: B::Focus) -> A::Source {
+        self.first.review(self.second.review(focus))
+    }
+}
@@ -0,0 +1,94 @@
+use std::{borrow::Cow, fmt};
+
+/// A single step in the path to a value that failed to extract.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathSegment {
+    /// A named field or key, rendered as `.name`.
+    Field(Cow<'static, str>),
+    /// A position in a sequence, rendered as `[index]`.
+    Index(usize),
+}
+
+impl From<&'static str> for PathSegment {
+    fn from(value: &'static str) -> Self {
+        PathSegment::Field(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(value: String) -> Self {
+        PathSegment::Field(Cow::Owned(value))
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(value: usize) -> Self {
+        PathSegment::Index(value)
+    }
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Field(name) => write!(f, ".{}", name),
+            PathSegment::Index(index) => write!(f, "[{}]", index),
+        }
+    }
+}
+
+/// The path to a value that failed to extract, from the outermost value inwards.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::EnumExtractError;
+///
+/// let error = EnumExtractError::new("Object", "Array")
+///     .context(3)
+///     .context("items")
+///     .context("body");
+/// assert_eq!(error.path().to_string(), "body.items[3]");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ErrorPath {
+    segments: Vec<PathSegment>,
+}
+
+impl ErrorPath {
+    /// Returns the segments of the path, from the outermost value inwards.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Returns `true` if the error happened at the top level value.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Adds a segment in front of the existing path.
+    pub(crate) fn push_front(&mut self, segment: PathSegment) {
+        self.segments.insert(0, segment);
+    }
+
+    /// Adds several segments in front of the existing path, keeping their order.
+    pub(crate) fn extend_front(&mut self, segments: impl IntoIterator<Item = PathSegment>) {
+        self.segments.splice(0..0, segments);
+    }
+}
+
+impl fmt::Display for ErrorPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                // the leading field does not need a separator
+                PathSegment::Field(name) if i == 0 => f.write_str(name)?,
+                _ => write!(f, "{}", segment)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+//! Items used by the code generated by `enum-extract`, which are not part of the public API.
+
+use std::fmt;
+
+/// Returns the `Debug` output of an enum for its errors, implemented by `#[derive(EnumExtract)]`.
+///
+/// `M` is the type that derived it, which is the enum itself unless it is the mirror of a remote enum.
+/// The generated code calls it by its full path, so that it never clashes with the enum's own functions.
+pub trait VariantDebug<M: ?Sized> {
+    /// Returns the `Debug` output of the value, or `None` if it does not implement `Debug`.
+    fn variant_debug(&self) -> Option<String>;
+}
+
+/// Names the variants of a remote enum for its errors, implemented by `#[derive(EnumExtract)]` on a mirror with `#[extract(remote = "...")]`.
+///
+/// `M` is the mirror, since `VariantName` cannot be implemented for a type from another crate.
+pub trait RemoteVariantName<M: ?Sized> {
+    /// The names of the variants in the mirror, in the order they are declared.
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// Returns the name of the variant, or `"<unknown>"` if it is missing from the mirror.
+    fn variant_name(&self) -> &'static str;
+
+    /// Returns the user-facing name of the variant, or `"<unknown>"` if it is missing from the mirror.
+    fn variant_display_name(&self) -> &'static str;
+}
+
+/// Returns a copy of a field for the `copied_[variant]` functions.
+///
+/// It is called by its full path, so that it is never hidden by a field with the same name.
+#[inline(always)]
+pub fn copy<T: Copy>(value: &T) -> T {
+    *value
+}
+
+/// Wraps a value to capture its `Debug` output only if it implements `Debug`.
+///
+/// `(&&Capture(&value)).capture_debug()` resolves to [`CaptureDebug`] if the value implements `Debug`,
+/// and falls back to [`CaptureNone`] otherwise.
+/// In generic code, the value is only captured if its `Debug` implementation does not depend on the generic parameters.
+pub struct Capture<'a, T: ?Sized>(pub &'a T);
+
+/// Captures the `Debug` output of a value that implements `Debug`.
+pub trait CaptureDebug {
+    /// Returns the `Debug` output of the value.
+    fn capture_debug(&self) -> Option<String>;
+}
+
+impl<T: fmt::Debug + ?Sized> CaptureDebug for &Capture<'_, T> {
+    fn capture_debug(&self) -> Option<String> {
+        Some(format!("{:?}", self.0))
+    }
+}
+
+/// Captures nothing for a value that does not implement `Debug`.
+pub trait CaptureNone {
+    /// Returns `None`.
+    fn capture_debug(&self) -> Option<String>;
+}
+
+impl<T: ?Sized> CaptureNone for Capture<'_, T> {
+    fn capture_debug(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The `Debug` output of a field, or `None` if the field is redacted.
+pub type FieldDebug = Option<String>;
+
+/// Renders a tuple variant in the same way as `#[derive(Debug)]`, with redacted fields hidden.
+pub fn render_tuple(variant: &str, fields: &[FieldDebug]) -> String {
+    format!("{:?}", DebugTuple(variant, fields))
+}
+
+/// Renders a struct variant in the same way as `#[derive(Debug)]`, with redacted fields hidden.
+pub fn render_struct(variant: &str, fields: &[(&str, FieldDebug)]) -> String {
+    format!("{:?}", DebugStruct(variant, fields))
+}
+
+struct DebugTuple<'a>(&'a str, &'a [FieldDebug]);
+
+impl fmt::Debug for DebugTuple<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tuple = f.debug_tuple(self.0);
+        for field in self.1 {
+            tuple.field(&Rendered(field.as_deref()));
+        }
+        tuple.finish()
+    }
+}
+
+struct DebugStruct<'a>(&'a str, &'a [(&'a str, FieldDebug)]);
+
+impl fmt::Debug for DebugStruct<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct(self.0);
+        for (name, field) in self.1 {
+            debug.field(name, &Rendered(field.as_deref()));
+        }
+        debug.finish()
+    }
+}
+
+/// Writes already rendered `Debug` output as is, or `<redacted>`.
+struct Rendered<'a>(Option<&'a str>);
+
+impl fmt::Debug for Rendered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.unwrap_or("<redacted>"))
+    }
+}
@@ -0,0 +1,44 @@
+/// Names the variants of an enum, implemented by `#[derive(EnumExtract)]`.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_extract_error::VariantName;
+///
+/// enum Number {
+///     One,
+///     Two(u32),
+/// }
+///
+/// // normally implemented by `#[derive(EnumExtract)]`
+/// impl VariantName for Number {
+///     const VARIANT_NAMES: &'static [&'static str] = &["One", "Two"];
+///
+///     fn variant_name(&self) -> &'static str {
+///         match self {
+///             Number::One => "One",
+///             Number::Two(_) => "Two",
+///         }
+///     }
+/// }
+///
+/// fn log_variant(value: &impl VariantName) -> String {
+///     format!("got {}", value.variant_name())
+/// }
+///
+/// assert_eq!(log_variant(&Number::Two(2)), "got Two");
+/// assert_eq!(Number::VARIANT_NAMES, ["One", "Two"]);
+/// ```
+pub trait VariantName {
+    /// The names of every variant, in declaration order.
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// Returns the name of the variant.
+    fn variant_name(&self) -> &'static str;
+
+    /// Returns the user-facing name of the variant, set with `#[extract(display = "...")]`,
+    /// which defaults to [`VariantName::variant_name`].
+    fn variant_display_name(&self) -> &'static str {
+        self.variant_name()
+    }
+}
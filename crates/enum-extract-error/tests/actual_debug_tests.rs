@@ -0,0 +1,44 @@
+#![cfg(feature = "alloc")]
+
+use enum_extract_error::{EnumExtractError, EnumExtractValueError};
+
+#[test]
+fn new_error_should_not_have_debug_output() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two");
+
+    // act
+    let actual_debug = error.actual_debug();
+
+    // assert
+    assert!(actual_debug.is_none());
+}
+
+#[test]
+fn or_should_keep_debug_output_of_other() {
+    // arrange
+    let first = EnumExtractError::new("One", "Three").with_actual_debug("Three(3)");
+    let second = EnumExtractError::new("Two", "Three").with_actual_debug("Three(3)");
+
+    // act
+    let error = first.or(second);
+
+    // assert
+    assert_eq!(error.actual_debug(), Some("Three(3)"));
+}
+
+#[test]
+fn debug_output_should_not_change_display_or_equality() {
+    // arrange
+    let error = EnumExtractValueError::new("One", "Two", 2);
+
+    // act
+    let with_debug = EnumExtractValueError::from_plain_error(
+        error.source.clone().with_actual_debug("Two(2)"),
+        2,
+    );
+
+    // assert
+    assert_eq!(with_debug.to_string(), "expected One, got Two");
+    assert_eq!(with_debug, error);
+}
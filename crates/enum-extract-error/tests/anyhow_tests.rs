@@ -0,0 +1,52 @@
+#![cfg(feature = "anyhow")]
+
+use std::rc::Rc;
+
+use enum_extract_error::{AnyhowResultExt, EnumExtractError, EnumExtractValueError};
+
+#[test]
+fn into_anyhow_should_add_context() {
+    // arrange
+    let result: Result<u32, EnumExtractError> = Err(EnumExtractError::new("One", "Two"));
+
+    // act
+    let error = result.into_anyhow("failed to read number").unwrap_err();
+
+    // assert
+    assert_eq!(error.to_string(), "failed to read number");
+    assert_eq!(
+        error.downcast_ref::<EnumExtractError>(),
+        Some(&EnumExtractError::new("One", "Two"))
+    );
+}
+
+#[test]
+fn into_anyhow_should_drop_value_of_value_error() {
+    // arrange
+    // `Rc` is not `Send`, so it could not be kept in an `anyhow::Error`
+    let result: Result<u32, EnumExtractValueError<Rc<u32>>> =
+        Err(EnumExtractValueError::new("One", "Two", Rc::new(2)));
+
+    // act
+    let error = result
+        .with_anyhow_context(|| format!("failed to read {}", "number"))
+        .unwrap_err();
+
+    // assert
+    assert_eq!(error.to_string(), "failed to read number");
+    assert_eq!(error.root_cause().to_string(), "expected One, got Two");
+}
+
+#[test]
+fn into_anyhow_should_not_change_ok() {
+    // arrange
+    let result: Result<u32, EnumExtractError> = Ok(1);
+
+    // act
+    let value = result
+        .with_anyhow_context(|| -> &str { unreachable!() })
+        .unwrap();
+
+    // assert
+    assert_eq!(value, 1);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "backtrace")]
+
+use enum_extract_error::{EnumExtractError, EnumExtractValueError};
+
+#[test]
+fn new_should_capture_backtrace() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two");
+
+    // act
+    let backtrace = error.backtrace();
+
+    // assert
+    assert!(backtrace.is_some());
+}
+
+#[test]
+fn clone_should_share_backtrace() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two");
+
+    // act
+    let clone = error.clone();
+
+    // assert
+    assert!(std::ptr::eq(
+        error.backtrace().unwrap(),
+        clone.backtrace().unwrap()
+    ));
+}
+
+#[test]
+fn value_error_should_expose_backtrace_of_source() {
+    // arrange
+    let error = EnumExtractValueError::new("One", "Two", 2);
+
+    // act
+    let backtrace = error.backtrace();
+
+    // assert
+    assert!(std::ptr::eq(
+        backtrace.unwrap(),
+        error.source.backtrace().unwrap()
+    ));
+}
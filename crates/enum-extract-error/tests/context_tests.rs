@@ -0,0 +1,150 @@
+use enum_extract_error::{EnumExtractError, EnumExtractValueError, PathSegment, ResultExt};
+
+#[test]
+fn error_without_context_should_not_have_path() {
+    // arrange
+    let error = EnumExtractError::new("Object", "Array");
+
+    // act
+    let message = error.to_string();
+
+    // assert
+    assert!(error.path().is_empty());
+    assert_eq!(message, "expected Object, got Array");
+}
+
+#[test]
+fn context_should_add_segments_from_inside_out() {
+    // arrange
+    let error = EnumExtractError::new("Object", "Array");
+
+    // act
+    let error = error.context(3).context("items").context("body");
+
+    // assert
+    assert_eq!(
+        error.path().segments(),
+        &[
+            PathSegment::from("body"),
+            PathSegment::from("items"),
+            PathSegment::Index(3),
+        ]
+    );
+    assert_eq!(
+        error.to_string(),
+        "at body.items[3]: expected Object, got Array"
+    );
+}
+
+#[test]
+fn with_path_should_add_segments_in_order() {
+    // arrange
+    let error = EnumExtractError::new("Object", "Array").context(3);
+
+    // act
+    let error = error.with_path(["body".to_string(), "items".to_string()]);
+
+    // assert
+    assert_eq!(error.path().to_string(), "body.items[3]");
+}
+
+#[test]
+fn result_ext_should_add_context_to_error() {
+    // arrange
+    let result: Result<u32, EnumExtractError> = Err(EnumExtractError::new("Object", "Array"));
+
+    // act
+    let result = result.context(0).context("items");
+
+    // assert
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "at items[0]: expected Object, got Array"
+    );
+}
+
+#[test]
+fn result_ext_should_not_change_ok() {
+    // arrange
+    let result: Result<u32, EnumExtractError> = Ok(1);
+
+    // act
+    let result = result.context("items");
+
+    // assert
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn value_error_context_should_be_preserved_by_conversion() {
+    // arrange
+    let result: Result<u32, EnumExtractValueError<i32>> =
+        Err(EnumExtractValueError::new("One", "Two", 2));
+
+    // act
+    let error: EnumExtractError = result.context("number").unwrap_err().into();
+
+    // assert
+    assert_eq!(error.to_string(), "at number: expected One, got Two");
+}
+
+#[test]
+fn path_starting_with_index_should_render_brackets() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two")
+        .context("name")
+        .context(1);
+
+    // act
+    let path = error.path().to_string();
+
+    // assert
+    assert_eq!(path, "[1].name");
+}
+
+#[test]
+fn within_should_qualify_variant_names() {
+    // arrange
+    let error = EnumExtractError::new_one_of(["A", "B"], "C");
+
+    // act
+    let error = error.within("Inner");
+
+    // assert
+    assert_eq!(
+        error.to_string(),
+        "expected one of [Inner::A, Inner::B], got Inner::C"
+    );
+    assert_eq!(error.expected_display(), "one of [Inner::A, Inner::B]");
+    assert_eq!(error.actual_display(), "Inner::C");
+    assert!(error.path().is_empty());
+}
+
+#[test]
+fn within_should_keep_plain_variant_names() {
+    // arrange
+    let error = EnumExtractError::new_one_of(["A", "B"], "C");
+
+    // act
+    let error = error.within("Inner").within("Outer");
+
+    // assert
+    assert_eq!(error.expected().names(), &["A", "B"]);
+    assert_eq!(error.actual(), "C");
+    assert_eq!(
+        error.to_string(),
+        "expected one of [Outer::Inner::A, Outer::Inner::B], got Outer::Inner::C"
+    );
+}
+
+#[test]
+fn within_should_not_change_display_names() {
+    // arrange
+    let error = EnumExtractError::new("A", "B").with_display("an a", "a b");
+
+    // act
+    let error = error.within("Inner");
+
+    // assert
+    assert_eq!(error.to_string(), "expected an a, got a b");
+}
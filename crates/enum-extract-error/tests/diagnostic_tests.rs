@@ -0,0 +1,82 @@
+#![cfg(feature = "miette")]
+
+use enum_extract_error::{EnumExtractError, EnumExtractValueError, ExtractErrors};
+use miette::Diagnostic;
+
+#[test]
+fn error_should_have_code_of_kind() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two");
+
+    // act
+    let code = error.code().unwrap().to_string();
+
+    // assert
+    assert_eq!(code, "enum_extract::wrong_variant");
+}
+
+#[test]
+fn nested_error_should_have_code_of_root_kind() {
+    // arrange
+    let error = EnumExtractError::new_missing_key("name").context("user");
+
+    // act
+    let code = error.code().unwrap().to_string();
+
+    // assert
+    assert_eq!(code, "enum_extract::missing_key");
+    assert!(error.help().is_none());
+}
+
+#[test]
+fn error_should_list_valid_variants_in_help() {
+    // arrange
+    let single = EnumExtractError::new("One", "Three");
+    let several = EnumExtractError::new_one_of(["One", "Two"], "Three");
+
+    // act
+    let single_help = single.help().unwrap().to_string();
+    let several_help = several.help().unwrap().to_string();
+
+    // assert
+    assert_eq!(single_help, "valid variant: One");
+    assert_eq!(several_help, "valid variants: One, Two");
+}
+
+#[test]
+fn value_error_should_report_source() {
+    // arrange
+    let error = EnumExtractValueError::new("One", "Two", 2);
+
+    // act
+    let code = error.code().unwrap().to_string();
+    let help = error.help().unwrap().to_string();
+
+    // assert
+    assert_eq!(code, "enum_extract::wrong_variant");
+    assert_eq!(help, "valid variant: One");
+}
+
+#[test]
+fn errors_should_relate_every_error() {
+    // arrange
+    let errors: ExtractErrors = [
+        EnumExtractError::new("One", "Two"),
+        EnumExtractError::new("Three", "Four"),
+    ]
+    .into_iter()
+    .collect();
+
+    // act
+    let related: Vec<String> = errors
+        .related()
+        .unwrap()
+        .map(|error| error.to_string())
+        .collect();
+
+    // assert
+    assert_eq!(
+        related,
+        ["expected One, got Two", "expected Three, got Four"]
+    );
+}
@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+use enum_extract_error::{EnumExtractError, EnumExtractValueError};
+
+#[test]
+fn errors_with_same_failure_should_be_equal() {
+    // arrange
+    let first = EnumExtractError::new("One", "Two");
+    let second = EnumExtractError::new("One", "Two".to_string());
+
+    // act
+    let equal = first == second;
+
+    // assert
+    assert!(equal);
+}
+
+#[test]
+fn errors_with_different_paths_should_not_be_equal() {
+    // arrange
+    let first = EnumExtractError::new("One", "Two").context("first");
+    let second = EnumExtractError::new("One", "Two").context("second");
+
+    // act
+    let equal = first == second;
+
+    // assert
+    assert!(!equal);
+}
+
+// the backtrace captured with the `backtrace` feature is not part of the hash
+#[allow(clippy::mutable_key_type)]
+#[test]
+fn equal_errors_should_hash_equally() {
+    // arrange
+    let mut set = HashSet::new();
+
+    // act
+    set.insert(EnumExtractError::new("One", "Two"));
+    set.insert(EnumExtractError::new("One", "Two"));
+    set.insert(EnumExtractError::new("One", "Three"));
+
+    // assert
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn value_errors_should_compare_values() {
+    // arrange
+    let first = EnumExtractValueError::new("One", "Two", 1);
+    let second = EnumExtractValueError::new("One", "Two", 2);
+
+    // act
+    let equal = first == second;
+
+    // assert
+    assert!(!equal);
+    assert_eq!(first, EnumExtractValueError::new("One", "Two", 1));
+}
@@ -0,0 +1,85 @@
+use enum_extract_error::{
+    try_all, EnumExtractError, EnumExtractValueError, ExtractErrors, ResultExt,
+};
+
+#[test]
+fn empty_errors_should_convert_to_ok() {
+    // arrange
+    let errors = ExtractErrors::new();
+
+    // act
+    let result = errors.into_result();
+
+    // assert
+    assert!(result.is_ok());
+}
+
+#[test]
+fn check_should_record_errors_and_return_values() {
+    // arrange
+    let mut errors = ExtractErrors::new();
+    let ok: Result<u32, EnumExtractError> = Ok(1);
+    let err: Result<u32, EnumExtractValueError<i32>> =
+        Err(EnumExtractValueError::new("One", "Two", 2));
+
+    // act
+    let ok = errors.check(ok);
+    let err = errors.check(err.context("number"));
+
+    // assert
+    assert_eq!(ok, Some(1));
+    assert_eq!(err, None);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors.errors()[0].path().to_string(), "number");
+}
+
+#[test]
+fn errors_should_render_multi_line_report() {
+    // arrange
+    let errors: ExtractErrors = [
+        EnumExtractError::new("One", "Two").context("first"),
+        EnumExtractError::new("Three", "Four"),
+    ]
+    .into_iter()
+    .collect();
+
+    // act
+    let report = errors.to_string();
+
+    // assert
+    assert_eq!(
+        report,
+        "2 extraction errors:\n  at first: expected One, got Two\n  expected Three, got Four"
+    );
+}
+
+#[test]
+fn try_all_should_return_all_values() {
+    // arrange
+    let first: Result<u32, EnumExtractError> = Ok(1);
+    let second: Result<&str, EnumExtractError> = Ok("two");
+    let third: Result<bool, EnumExtractValueError<i32>> = Ok(true);
+
+    // act
+    let result = try_all!(first, second, third);
+
+    // assert
+    assert_eq!(result.unwrap(), (1, "two", true));
+}
+
+#[test]
+fn try_all_should_return_every_error() {
+    // arrange
+    let first: Result<u32, EnumExtractError> = Err(EnumExtractError::new("One", "Two"));
+    let second: Result<&str, EnumExtractError> = Ok("two");
+    let third: Result<bool, EnumExtractError> = Err(EnumExtractError::new("Three", "Four"));
+
+    // act
+    let result = try_all!(first, second, third);
+
+    // assert
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.errors()[0].actual, "Two");
+    assert_eq!(errors.errors()[1].actual, "Four");
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "hook")]
+
+use std::sync::Mutex;
+
+use enum_extract_error::{
+    set_mismatch_hook, take_mismatch_hook, EnumExtractError, EnumExtractValueError, ErrorKind,
+};
+
+static MISMATCHES: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+// the hook is global, so the tests must not run at the same time
+static SERIAL: Mutex<()> = Mutex::new(());
+
+fn record_mismatch(error: &EnumExtractError) {
+    let expected = error.expected().to_string();
+    MISMATCHES
+        .lock()
+        .unwrap()
+        .push((expected, error.actual().to_string()));
+}
+
+fn take_mismatches() -> Vec<(String, String)> {
+    std::mem::take(&mut *MISMATCHES.lock().unwrap())
+}
+
+#[test]
+fn hook_should_be_called_for_new_errors() {
+    // arrange
+    let _serial = SERIAL.lock().unwrap();
+    take_mismatches();
+    set_mismatch_hook(record_mismatch);
+
+    // act
+    let _ = EnumExtractError::new("One", "Two");
+    let _ = EnumExtractError::new_one_of(["One", "Two"], "Three");
+    let _ = EnumExtractValueError::new("One", "Two", 2);
+
+    // assert
+    take_mismatch_hook();
+    assert_eq!(
+        take_mismatches(),
+        vec![
+            ("One".to_string(), "Two".to_string()),
+            ("one of [One, Two]".to_string(), "Three".to_string()),
+            ("One".to_string(), "Two".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn hook_should_see_kind_of_error() {
+    // arrange
+    let _serial = SERIAL.lock().unwrap();
+    set_mismatch_hook(|error| assert_eq!(error.kind(), ErrorKind::MissingKey));
+
+    // act
+    let error = EnumExtractError::new_missing_key("name");
+
+    // assert
+    take_mismatch_hook();
+    assert_eq!(error.kind(), ErrorKind::MissingKey);
+}
+
+#[test]
+fn taken_hook_should_not_be_called() {
+    // arrange
+    let _serial = SERIAL.lock().unwrap();
+    take_mismatches();
+    set_mismatch_hook(record_mismatch);
+
+    // act
+    let hook = take_mismatch_hook();
+    let _ = EnumExtractError::new("One", "Two");
+
+    // assert
+    assert!(hook.is_some());
+    assert!(take_mismatches().is_empty());
+}
@@ -0,0 +1,36 @@
+use std::io;
+
+use enum_extract_error::{EnumExtractError, EnumExtractValueError};
+
+#[test]
+fn error_should_convert_into_invalid_data() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two");
+
+    // act
+    let io_error = io::Error::from(error);
+
+    // assert
+    assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(io_error.to_string(), "expected One, got Two");
+    assert_eq!(
+        io_error
+            .get_ref()
+            .unwrap()
+            .downcast_ref::<EnumExtractError>(),
+        Some(&EnumExtractError::new("One", "Two"))
+    );
+}
+
+#[test]
+fn value_error_should_convert_into_invalid_data() {
+    // arrange
+    let error = EnumExtractValueError::new("One", "Two", 2);
+
+    // act
+    let io_error = io::Error::from(error);
+
+    // assert
+    assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(io_error.to_string(), "expected One, got Two");
+}
@@ -0,0 +1,66 @@
+use enum_extract_error::{EnumExtractError, ErrorKind, Expected};
+
+#[test]
+fn new_should_be_wrong_variant() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two");
+
+    // act
+    let kind = error.kind();
+
+    // assert
+    assert_eq!(kind, ErrorKind::WrongVariant);
+    assert_eq!(error.expected(), &Expected::Variant("One".into()));
+    assert_eq!(error.actual(), "Two");
+}
+
+#[test]
+fn new_one_of_should_be_wrong_variant_of() {
+    // arrange
+    let error = EnumExtractError::new_one_of(["One", "Two"], "Three");
+
+    // act
+    let kind = error.kind();
+
+    // assert
+    assert_eq!(kind, ErrorKind::WrongVariantOf);
+}
+
+#[test]
+fn or_should_be_wrong_variant_of() {
+    // arrange
+    let error = EnumExtractError::new("One", "Three");
+
+    // act
+    let error = error.or(EnumExtractError::new("Two", "Three"));
+
+    // assert
+    assert_eq!(error.kind(), ErrorKind::WrongVariantOf);
+}
+
+#[test]
+fn missing_key_should_name_key() {
+    // arrange
+    let error = EnumExtractError::new_missing_key("name");
+
+    // act
+    let message = error.to_string();
+
+    // assert
+    assert_eq!(error.kind(), ErrorKind::MissingKey);
+    assert_eq!(message, "missing key name");
+}
+
+#[test]
+fn error_with_path_should_be_nested() {
+    // arrange
+    let error = EnumExtractError::new_missing_key("name");
+
+    // act
+    let error = error.context("body");
+
+    // assert
+    assert_eq!(error.kind(), ErrorKind::Nested);
+    assert_eq!(error.root_kind(), ErrorKind::MissingKey);
+    assert_eq!(error.to_string(), "at body: missing key name");
+}
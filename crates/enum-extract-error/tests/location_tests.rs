@@ -0,0 +1,44 @@
+#![cfg(feature = "location")]
+
+use enum_extract_error::{EnumExtractError, EnumExtractValueError};
+
+#[test]
+fn new_should_record_caller_location() {
+    // arrange
+    let line = line!() + 3;
+
+    // act
+    let error = EnumExtractError::new("One", "Two");
+
+    // assert
+    let location = error.location().unwrap();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.line(), line);
+}
+
+#[test]
+fn alternate_display_should_include_location() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two");
+    let location = error.location().unwrap();
+
+    // act
+    let message = format!("{:#}", error);
+
+    // assert
+    assert_eq!(message, format!("expected One, got Two (at {})", location));
+    assert_eq!(error.to_string(), "expected One, got Two");
+}
+
+#[test]
+fn value_error_should_forward_alternate_display() {
+    // arrange
+    let error = EnumExtractValueError::new("One", "Two", 2);
+    let location = error.source.location().unwrap();
+
+    // act
+    let message = format!("{:#}", error);
+
+    // assert
+    assert_eq!(message, format!("expected One, got Two (at {})", location));
+}
@@ -0,0 +1,190 @@
+use enum_extract_error::{EnumExtractError, FieldLens, Optic, PathSegment, Prism, VariantPrism};
+
+#[derive(Debug, PartialEq)]
+struct Request {
+    body: Body,
+}
+
+#[derive(Debug, PartialEq)]
+enum Body {
+    Json(Json),
+    Text(String),
+}
+
+#[derive(Debug, PartialEq)]
+enum Json {
+    Number(i64),
+    String(String),
+}
+
+const BODY: FieldLens<Request, Body> = FieldLens::new(
+    "body",
+    |request| &request.body,
+    |request| &mut request.body,
+    |request| request.body,
+);
+
+const JSON: VariantPrism<Body, Json> = VariantPrism::new(
+    "Json",
+    |body| match body {
+        Body::Json(inner) => Ok(inner),
+        Body::Text(_) => Err(EnumExtractError::new("Json", "Text")),
+    },
+    |body| match body {
+        Body::Json(inner) => Ok(inner),
+        Body::Text(_) => Err(EnumExtractError::new("Json", "Text")),
+    },
+    |body| match body {
+        Body::Json(inner) => Ok(inner),
+        Body::Text(_) => Err(EnumExtractError::new("Json", "Text")),
+    },
+    Body::Json,
+);
+
+const NUMBER: VariantPrism<Json, i64> = VariantPrism::new(
+    "Number",
+    |json| match json {
+        Json::Number(inner) => Ok(inner),
+        Json::String(_) => Err(EnumExtractError::new("Number", "String")),
+    },
+    |json| match json {
+        Json::Number(inner) => Ok(inner),
+        Json::String(_) => Err(EnumExtractError::new("Number", "String")),
+    },
+    |json| match json {
+        Json::Number(inner) => Ok(inner),
+        Json::String(_) => Err(EnumExtractError::new("Number", "String")),
+    },
+    Json::Number,
+);
+
+#[test]
+fn variant_prism_should_preview_matching_variant() {
+    // arrange
+    let body = Body::Json(Json::Number(1));
+
+    // act
+    let json = JSON.preview(&body);
+
+    // assert
+    assert_eq!(json.unwrap(), &Json::Number(1));
+}
+
+#[test]
+fn variant_prism_should_return_error_without_path_for_other_variant() {
+    // arrange
+    let body = Body::Text("text".to_string());
+
+    // act
+    let error = JSON.preview(&body).unwrap_err();
+
+    // assert
+    assert!(error.path().is_empty());
+    assert_eq!(error.to_string(), "expected Json, got Text");
+}
+
+#[test]
+fn variant_prism_should_review_focus() {
+    // arrange
+    let focus = 1;
+
+    // act
+    let json = NUMBER.review(focus);
+
+    // assert
+    assert_eq!(json, Json::Number(1));
+}
+
+#[test]
+fn composed_optic_should_preview_nested_value() {
+    // arrange
+    let request = Request {
+        body: Body::Json(Json::Number(1)),
+    };
+    let number = BODY.then(JSON).then(NUMBER);
+
+    // act
+    let value = number.preview(&request);
+
+    // assert
+    assert_eq!(value.unwrap(), &1);
+}
+
+#[test]
+fn composed_optic_should_add_steps_before_failure_to_path() {
+    // arrange
+    let request = Request {
+        body: Body::Json(Json::String("one".to_string())),
+    };
+    let number = BODY.then(JSON).then(NUMBER);
+
+    // act
+    let error = number.preview(&request).unwrap_err();
+
+    // assert
+    assert_eq!(
+        error.path().segments(),
+        &[PathSegment::from("body"), PathSegment::from("Json")]
+    );
+    assert_eq!(
+        error.to_string(),
+        "at body.Json: expected Number, got String"
+    );
+}
+
+#[test]
+fn composed_optic_should_not_add_failing_step_to_path() {
+    // arrange
+    let request = Request {
+        body: Body::Text("text".to_string()),
+    };
+    let number = BODY.then(JSON).then(NUMBER);
+
+    // act
+    let error = number.preview(&request).unwrap_err();
+
+    // assert
+    assert_eq!(error.to_string(), "at body: expected Json, got Text");
+}
+
+#[test]
+fn composed_optic_should_preview_mut_nested_value() {
+    // arrange
+    let mut request = Request {
+        body: Body::Json(Json::Number(1)),
+    };
+    let number = BODY.then(JSON).then(NUMBER);
+
+    // act
+    *number.preview_mut(&mut request).unwrap() += 1;
+
+    // assert
+    assert_eq!(request.body, Body::Json(Json::Number(2)));
+}
+
+#[test]
+fn composed_optic_should_into_preview_nested_value() {
+    // arrange
+    let request = Request {
+        body: Body::Json(Json::Number(1)),
+    };
+    let number = BODY.then(JSON).then(NUMBER);
+
+    // act
+    let value = number.into_preview(request);
+
+    // assert
+    assert_eq!(value.unwrap(), 1);
+}
+
+#[test]
+fn composed_prism_should_review_nested_focus() {
+    // arrange
+    let number = JSON.then(NUMBER);
+
+    // act
+    let body = number.review(1);
+
+    // assert
+    assert_eq!(body, Body::Json(Json::Number(1)));
+}
@@ -0,0 +1,116 @@
+use enum_extract_error::{EnumExtractValueError, Expected, ValueResultExt};
+
+#[derive(Debug, PartialEq)]
+enum Number {
+    One(u32),
+    Two(u32),
+    Three(u32),
+}
+
+impl Number {
+    fn variant_name(&self) -> &'static str {
+        match self {
+            Number::One(_) => "One",
+            Number::Two(_) => "Two",
+            Number::Three(_) => "Three",
+        }
+    }
+
+    fn into_one(self) -> Result<u32, EnumExtractValueError<Self>> {
+        match self {
+            Number::One(inner) => Ok(inner),
+            _ => Err(EnumExtractValueError::new("One", self.variant_name(), self)),
+        }
+    }
+
+    fn into_two(self) -> Result<u32, EnumExtractValueError<Self>> {
+        match self {
+            Number::Two(inner) => Ok(inner),
+            _ => Err(EnumExtractValueError::new("Two", self.variant_name(), self)),
+        }
+    }
+
+    fn into_three(self) -> Result<u32, EnumExtractValueError<Self>> {
+        match self {
+            Number::Three(inner) => Ok(inner),
+            _ => Err(EnumExtractValueError::new(
+                "Three",
+                self.variant_name(),
+                self,
+            )),
+        }
+    }
+}
+
+#[test]
+fn or_try_should_return_first_success() {
+    // arrange
+    let number = Number::One(1);
+
+    // act
+    let result = number.into_one().or_try(Number::into_two);
+
+    // assert
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn or_try_should_fall_back_to_next_extraction() {
+    // arrange
+    let number = Number::Two(2);
+
+    // act
+    let result = number.into_one().or_try(Number::into_two);
+
+    // assert
+    assert_eq!(result.unwrap(), 2);
+}
+
+#[test]
+fn or_try_should_list_every_attempted_variant() {
+    // arrange
+    let number = Number::Three(3);
+
+    // act
+    let error = number.into_one().or_try(Number::into_two).unwrap_err();
+
+    // assert
+    assert_eq!(
+        error.source.expected,
+        Expected::AnyOf {
+            names: vec!["One".into(), "Two".into()],
+            group: false,
+        }
+    );
+    assert_eq!(error.source.actual, "Three");
+    assert_eq!(error.value, Number::Three(3));
+    assert_eq!(error.to_string(), "expected One or Two, got Three");
+}
+
+#[test]
+fn or_try_should_chain_more_than_two_extractions() {
+    // arrange
+    let number = Number::One(1);
+
+    // act
+    let result = number
+        .into_two()
+        .or_try(Number::into_three)
+        .or_try(Number::into_one);
+
+    // assert
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn expected_should_compare_equal_to_its_rendered_form() {
+    // arrange
+    let number = Number::Three(3);
+
+    // act
+    let error = number.into_one().or_try(Number::into_two).unwrap_err();
+
+    // assert
+    assert_eq!(error.source.expected, "One or Two");
+    assert_ne!(error.source.expected, "One");
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "serde")]
+
+use enum_extract_error::{
+    EnumExtractError, EnumExtractValueError, ExtractErrors, MissingFieldError,
+};
+
+#[test]
+fn error_should_round_trip_through_json() {
+    // arrange
+    let error = EnumExtractError::new("Object", "Array")
+        .context(3)
+        .context("items");
+
+    // act
+    let json = serde_json::to_string(&error).unwrap();
+    let deserialized: EnumExtractError = serde_json::from_str(&json).unwrap();
+
+    // assert
+    assert_eq!(deserialized, error);
+    assert_eq!(
+        deserialized.to_string(),
+        "at items[3]: expected Object, got Array"
+    );
+}
+
+#[test]
+fn error_should_serialize_names_as_strings() {
+    // arrange
+    let error = EnumExtractError::new("One", "Two");
+
+    // act
+    let json = serde_json::to_value(&error).unwrap();
+
+    // assert
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "expected": { "Variant": "One" },
+            "actual": "Two",
+            "kind": "WrongVariant",
+        })
+    );
+}
+
+#[test]
+fn value_error_should_round_trip_through_json() {
+    // arrange
+    let error = EnumExtractValueError::new_one_of(["One", "Two"], "Three", 3);
+
+    // act
+    let json = serde_json::to_string(&error).unwrap();
+    let deserialized: EnumExtractValueError<i32> = serde_json::from_str(&json).unwrap();
+
+    // assert
+    assert_eq!(deserialized, error);
+}
+
+#[test]
+fn other_errors_should_round_trip_through_json() {
+    // arrange
+    let missing_field = MissingFieldError::new("Rectangle", "width");
+    let errors: ExtractErrors = [EnumExtractError::new_missing_key("name")]
+        .into_iter()
+        .collect();
+
+    // act
+    let missing_field_json = serde_json::to_string(&missing_field).unwrap();
+    let errors_json = serde_json::to_string(&errors).unwrap();
+
+    // assert
+    assert_eq!(
+        serde_json::from_str::<MissingFieldError>(&missing_field_json).unwrap(),
+        missing_field
+    );
+    assert_eq!(
+        serde_json::from_str::<ExtractErrors>(&errors_json).unwrap(),
+        errors
+    );
+}
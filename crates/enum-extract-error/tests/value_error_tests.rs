@@ -1,3 +1,5 @@
+#![allow(clippy::let_unit_value)]
+
 use enum_extract_error::{EnumExtractError, EnumExtractValueError};
 
 #[test]
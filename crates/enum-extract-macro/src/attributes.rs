@@ -0,0 +1,314 @@
+use proc_macro2::Ident;
+use syn::{punctuated::Punctuated, Attribute, LitStr, Token};
+
+/// The name of the helper attribute used to configure the derive.
+const ATTRIBUTE_NAME: &str = "extract";
+
+/// Options set with `#[extract(...)]` on the enum itself.
+#[derive(Default)]
+pub struct EnumAttributes {
+    /// Groups of variants declared with `#[extract(group(Name = [A, B]))]`.
+    pub groups: Vec<VariantGroup>,
+    /// The traits derived for every group's enums, set with `#[extract(group_derive(Debug, Clone))]`.
+    pub group_derives: Vec<syn::Path>,
+    /// Whether `From` and `TryFrom` are implemented for every single-field variant, set with `#[extract(from)]`.
+    pub from: bool,
+    /// Whether `new_[variant]` constructors are generated for every variant, set with `#[extract(new)]`.
+    pub new: bool,
+    /// Whether constructors take `impl Into<T>` arguments, set with `#[extract(into)]`.
+    pub into: bool,
+    /// Whether builders are generated for every named variant, set with `#[extract(builder)]`.
+    pub builder: bool,
+    /// Whether `copied_[variant]` functions are generated, set with `#[extract(copy)]`.
+    pub copy: bool,
+    /// Whether `cloned_[variant]` functions are generated, set with `#[extract(clone)]`.
+    pub clone: bool,
+    /// The message template of every error, set with `#[extract(message = "...")]`.
+    pub message: Option<String>,
+    /// Whether the kind enum and the implementation of `EnumExtract` are generated,
+    /// set with `#[extract(kind)]` or `#[extract(kind = Name)]`.
+    pub kind: bool,
+    /// The name of the generated kind enum, set with `#[extract(kind = Name)]`.
+    pub kind_name: Option<Ident>,
+    /// Whether the module of marker types is public under the enum's name,
+    /// set with `#[extract(markers)]` or `#[extract(markers = name)]`.
+    pub markers: bool,
+    /// The name of the public module of marker types, set with `#[extract(markers = name)]`.
+    pub markers_name: Option<Ident>,
+    /// Whether a `Prism` constant is generated for every variant with one field, set with `#[extract(prisms)]`.
+    pub prisms: bool,
+    /// The path of the enum from another crate that this enum mirrors, set with `#[extract(remote = "other::Enum")]`.
+    pub remote: Option<syn::Path>,
+    /// The name of the trait that holds the enum's functions instead of an inherent impl, set with `#[extract(trait = "Name")]`.
+    pub extension_trait: Option<Ident>,
+}
+
+/// Options set with `#[extract(...)]` on a struct.
+#[derive(Default)]
+pub struct StructAttributes {
+    /// Whether a `FieldLens` constant is generated for every named field, set with `#[extract(lenses)]`.
+    pub lenses: bool,
+}
+
+/// Options set with `#[extract(...)]` on a variant.
+#[derive(Default)]
+pub struct VariantAttributes {
+    /// Whether `From` and `TryFrom` are implemented for this variant,
+    /// set with `#[extract(from)]` or `#[extract(no_from)]`.
+    pub from: Option<bool>,
+    /// Whether a `new_[variant]` constructor is generated for this variant, set with `#[extract(new)]`.
+    pub new: bool,
+    /// Whether the constructor takes `impl Into<T>` arguments, set with `#[extract(into)]`.
+    pub into: bool,
+    /// Whether a builder is generated for this variant, set with `#[extract(builder)]`.
+    pub builder: bool,
+    /// Whether a `copied_[variant]` function is generated, set with `#[extract(copy)]`.
+    pub copy: bool,
+    /// Whether a `cloned_[variant]` function is generated, set with `#[extract(clone)]`.
+    pub clone: bool,
+    /// Whether every field is returned through `Deref`, set with `#[extract(deref)]` or `#[extract(deref_mut)]`.
+    pub deref: bool,
+    /// Whether every field is returned through `DerefMut` by `as_[variant]_mut`, set with `#[extract(deref_mut)]`.
+    pub deref_mut: bool,
+    /// The user-facing name of the variant used in errors, set with `#[extract(display = "...")]`.
+    pub display: Option<String>,
+    /// The message template of errors that expected this variant, set with `#[extract(message = "...")]`.
+    pub message: Option<String>,
+    /// The variants of the inner enum that are extracted through this variant, set with `#[extract(nested(A, B))]`.
+    pub nested: Vec<Ident>,
+}
+
+/// Options set with `#[extract(...)]` on a field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    /// The value used when the field is not given to a constructor,
+    /// set with `#[extract(default)]` or `#[extract(default = expr)]`.
+    pub default: Option<syn::Expr>,
+    /// Whether the field is returned through `Deref`, set with `#[extract(deref)]` or `#[extract(deref_mut)]`.
+    pub deref: bool,
+    /// Whether the field is returned through `DerefMut` by `as_[variant]_mut`, set with `#[extract(deref_mut)]`.
+    pub deref_mut: bool,
+    /// Whether the field is hidden from the `Debug` output recorded in errors, set with `#[extract(redact)]`.
+    pub redact: bool,
+    /// The variants that a struct forwards functions for, when it delegates extraction to this field,
+    /// set with `#[extract(delegate(A, B))]`, or empty for every variant with `#[extract(delegate)]`.
+    pub delegate: Option<Vec<Ident>>,
+}
+
+/// A named group of variants.
+pub struct VariantGroup {
+    pub name: Ident,
+    pub variants: Vec<Ident>,
+}
+
+impl EnumAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in extract_attributes(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("group") {
+                    meta.parse_nested_meta(|group| {
+                        let name = group.path.require_ident()?.clone();
+
+                        let value = group.value()?;
+                        let content;
+                        syn::bracketed!(content in value);
+                        let variants = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+
+                        result.groups.push(VariantGroup {
+                            name,
+                            variants: variants.into_iter().collect(),
+                        });
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("group_derive") {
+                    meta.parse_nested_meta(|derive| {
+                        result.group_derives.push(derive.path.clone());
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("from") {
+                    result.from = true;
+                    Ok(())
+                } else if meta.path.is_ident("new") {
+                    result.new = true;
+                    Ok(())
+                } else if meta.path.is_ident("into") {
+                    result.into = true;
+                    Ok(())
+                } else if meta.path.is_ident("builder") {
+                    result.builder = true;
+                    Ok(())
+                } else if meta.path.is_ident("copy") {
+                    result.copy = true;
+                    Ok(())
+                } else if meta.path.is_ident("clone") {
+                    result.clone = true;
+                    Ok(())
+                } else if meta.path.is_ident("message") {
+                    result.message = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("kind") {
+                    result.kind = true;
+                    if meta.input.peek(Token![=]) {
+                        result.kind_name = Some(meta.value()?.parse()?);
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("markers") {
+                    result.markers = true;
+                    if meta.input.peek(Token![=]) {
+                        result.markers_name = Some(meta.value()?.parse()?);
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("prisms") {
+                    result.prisms = true;
+                    Ok(())
+                } else if meta.path.is_ident("remote") {
+                    result.remote = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("trait") {
+                    result.extension_trait = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported enum extract attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl StructAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in extract_attributes(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("lenses") {
+                    result.lenses = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported struct extract attribute, `#[extract(delegate)]` belongs on a field"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl VariantAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in extract_attributes(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("from") {
+                    result.from = Some(true);
+                    Ok(())
+                } else if meta.path.is_ident("no_from") {
+                    result.from = Some(false);
+                    Ok(())
+                } else if meta.path.is_ident("new") {
+                    result.new = true;
+                    Ok(())
+                } else if meta.path.is_ident("into") {
+                    result.into = true;
+                    Ok(())
+                } else if meta.path.is_ident("builder") {
+                    result.builder = true;
+                    Ok(())
+                } else if meta.path.is_ident("copy") {
+                    result.copy = true;
+                    Ok(())
+                } else if meta.path.is_ident("clone") {
+                    result.clone = true;
+                    Ok(())
+                } else if meta.path.is_ident("deref") {
+                    result.deref = true;
+                    Ok(())
+                } else if meta.path.is_ident("deref_mut") {
+                    result.deref = true;
+                    result.deref_mut = true;
+                    Ok(())
+                } else if meta.path.is_ident("display") {
+                    result.display = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("message") {
+                    result.message = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("nested") {
+                    let error = meta.error(
+                        "`#[extract(nested)]` requires the inner variants to extract, ex: `#[extract(nested(A, B))]`",
+                    );
+                    if !meta.input.peek(syn::token::Paren) {
+                        return Err(error);
+                    }
+                    let count = result.nested.len();
+                    meta.parse_nested_meta(|nested| {
+                        result.nested.push(nested.path.require_ident()?.clone());
+                        Ok(())
+                    })?;
+                    if result.nested.len() == count {
+                        return Err(error);
+                    }
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported variant extract attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl FieldAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in extract_attributes(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    result.default = Some(if meta.input.peek(Token![=]) {
+                        meta.value()?.parse()?
+                    } else {
+                        syn::parse_quote!(::core::default::Default::default())
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("deref") {
+                    result.deref = true;
+                    Ok(())
+                } else if meta.path.is_ident("deref_mut") {
+                    result.deref = true;
+                    result.deref_mut = true;
+                    Ok(())
+                } else if meta.path.is_ident("redact") {
+                    result.redact = true;
+                    Ok(())
+                } else if meta.path.is_ident("delegate") {
+                    let variants = result.delegate.get_or_insert_with(Vec::new);
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|variant| {
+                            variants.push(variant.path.require_ident()?.clone());
+                            Ok(())
+                        })?;
+                    }
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported field extract attribute"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Returns only the attributes that belong to this derive.
+fn extract_attributes(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME))
+}
@@ -0,0 +1,298 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, TokenStreamExt};
+use syn::{Fields, Generics, Type, Variant, Visibility};
+
+use crate::{
+    attributes::{EnumAttributes, FieldAttributes, VariantAttributes},
+    function_def::FunctionDef,
+    ErrorTypes,
+};
+
+/// Returns the constructor functions for a variant, which belong in the enum's impl block.
+pub fn all_constructor_functions(context: &ConstructorContext) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    if context.new {
+        tokens.append_all(constructor_new_variant(context));
+    }
+    if context.builder {
+        tokens.append_all(constructor_variant_builder(context));
+    }
+
+    tokens
+}
+
+/// Returns the builder for a variant, which belongs next to the enum.
+pub fn all_constructor_items(
+    context: &ConstructorContext,
+    error_types: &ErrorTypes,
+) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    if context.builder {
+        tokens.append_all(constructor_builder_struct(context));
+        tokens.append_all(constructor_builder_impl(
+            context,
+            &error_types.missing_field_err_type,
+        ));
+    }
+
+    tokens
+}
+
+pub struct ConstructorContext<'a> {
+    pub enum_name: &'a Ident,
+    /// The path used to construct the enum, which is the enum's own name unless it is a remote enum.
+    pub enum_path: &'a syn::Path,
+    pub visibility: &'a Visibility,
+    pub generics: &'a Generics,
+    pub variant: &'a Variant,
+    pub fields: Vec<ConstructorField<'a>>,
+    pub new: bool,
+    pub into: bool,
+    pub builder: bool,
+    pub builder_name: Ident,
+}
+
+pub struct ConstructorField<'a> {
+    /// The name of the argument or builder field that holds the value.
+    pub binding: Ident,
+    /// The name of the field, if it is a named field.
+    pub member: Option<&'a Ident>,
+    pub ty: &'a Type,
+    pub default: Option<syn::Expr>,
+}
+
+impl<'a> ConstructorContext<'a> {
+    pub fn new(
+        enum_name: &'a Ident,
+        enum_path: &'a syn::Path,
+        visibility: &'a Visibility,
+        generics: &'a Generics,
+        variant: &'a Variant,
+        enum_attributes: &EnumAttributes,
+        variant_attributes: &VariantAttributes,
+    ) -> syn::Result<Self> {
+        let mut fields = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let field_attributes = FieldAttributes::parse(&field.attrs)?;
+
+            fields.push(ConstructorField {
+                binding: match &field.ident {
+                    Some(ident) => ident.clone(),
+                    None => Ident::new(&format!("field_{}", i), Span::call_site()),
+                },
+                member: field.ident.as_ref(),
+                ty: &field.ty,
+                default: field_attributes.default,
+            });
+        }
+
+        let is_named = matches!(variant.fields, Fields::Named(_));
+        if variant_attributes.builder && !is_named {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                "`#[extract(builder)]` requires a variant with named fields",
+            ));
+        }
+
+        Ok(Self {
+            enum_name,
+            enum_path,
+            visibility,
+            generics,
+            variant,
+            fields,
+            new: enum_attributes.new || variant_attributes.new,
+            into: enum_attributes.into || variant_attributes.into,
+            builder: is_named && (enum_attributes.builder || variant_attributes.builder),
+            builder_name: format_ident!("{}{}Builder", enum_name, variant.ident),
+        })
+    }
+
+    /// Returns the type of an argument that sets the given field.
+    fn argument_type(&self, field: &ConstructorField) -> TokenStream {
+        let ty = field.ty;
+        if self.into {
+            quote!(impl ::core::convert::Into<#ty>)
+        } else {
+            quote!(#ty)
+        }
+    }
+
+    /// Returns an expression that converts an argument into the field's value.
+    fn argument_value(&self, field: &ConstructorField) -> TokenStream {
+        let binding = &field.binding;
+        if self.into {
+            quote!(::core::convert::Into::into(#binding))
+        } else {
+            quote!(#binding)
+        }
+    }
+
+    /// Returns an expression constructing the variant, with each field's value given by `value`.
+    fn construct(&self, value: impl Fn(&ConstructorField) -> TokenStream) -> TokenStream {
+        let enum_path = self.enum_path;
+        let variant_name = &self.variant.ident;
+        let values = self.fields.iter().map(|field| {
+            let value = value(field);
+            match field.member {
+                Some(member) => quote!(#member: #value),
+                None => value,
+            }
+        });
+
+        match &self.variant.fields {
+            Fields::Unit => quote!(#enum_path::#variant_name),
+            Fields::Unnamed(_) => quote!(#enum_path::#variant_name( #(#values),* )),
+            Fields::Named(_) => quote!(#enum_path::#variant_name{ #(#values),* }),
+        }
+    }
+}
+
+fn constructor_new_variant(context: &ConstructorContext) -> TokenStream {
+    let function_def = FunctionDef::new_constructor(context.enum_name, &context.variant.ident);
+    let function_name = function_def.declaration;
+    let docs = function_def.docs;
+
+    let arguments = context
+        .fields
+        .iter()
+        .filter(|field| field.default.is_none())
+        .map(|field| {
+            let binding = &field.binding;
+            let ty = context.argument_type(field);
+            quote!(#binding: #ty)
+        });
+
+    let value = context.construct(|field| match &field.default {
+        Some(default) => quote!(#default),
+        None => context.argument_value(field),
+    });
+
+    quote!(
+        #[doc = #docs]
+        #[inline]
+        pub fn #function_name(#(#arguments),*) -> Self {
+            #value
+        }
+    )
+}
+
+fn constructor_variant_builder(context: &ConstructorContext) -> TokenStream {
+    let function_def = FunctionDef::new_builder(context.enum_name, &context.variant.ident);
+    let function_name = function_def.declaration;
+    let docs = function_def.docs;
+    let builder_name = &context.builder_name;
+    let (_, ty_generics, _) = context.generics.split_for_impl();
+
+    quote!(
+        #[doc = #docs]
+        #[inline]
+        pub fn #function_name() -> #builder_name #ty_generics {
+            ::core::default::Default::default()
+        }
+    )
+}
+
+fn constructor_builder_struct(context: &ConstructorContext) -> TokenStream {
+    let visibility = context.visibility;
+    let enum_name = context.enum_name;
+    let enum_path = context.enum_path;
+    let builder_name = &context.builder_name;
+    let (impl_generics, ty_generics, where_clause) = context.generics.split_for_impl();
+    let docs = format!(
+        "A builder for `{}::{}`, returned by [`{}::{}`].",
+        enum_name,
+        context.variant.ident,
+        enum_name,
+        FunctionDef::new_builder(enum_name, &context.variant.ident).declaration,
+    );
+
+    let fields = context.fields.iter().map(|field| {
+        let binding = &field.binding;
+        let ty = field.ty;
+        quote!(#binding: ::core::option::Option<#ty>,)
+    });
+    let defaults = context.fields.iter().map(|field| {
+        let binding = &field.binding;
+        quote!(#binding: ::core::option::Option::None,)
+    });
+
+    quote!(
+        #[doc = #docs]
+        #visibility struct #builder_name #impl_generics #where_clause {
+            #(#fields)*
+            _marker: ::core::marker::PhantomData<fn() -> #enum_path #ty_generics>,
+        }
+
+        impl #impl_generics ::core::default::Default for #builder_name #ty_generics #where_clause {
+            #[inline]
+            fn default() -> Self {
+                Self {
+                    #(#defaults)*
+                    _marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+    )
+}
+
+fn constructor_builder_impl(
+    context: &ConstructorContext,
+    missing_field_err_type: &Type,
+) -> TokenStream {
+    let enum_name = context.enum_name;
+    let enum_path = context.enum_path;
+    let variant_name = &context.variant.ident;
+    let builder_name = &context.builder_name;
+    let (impl_generics, ty_generics, where_clause) = context.generics.split_for_impl();
+    let build_docs = format!(
+        "Constructs the `{}::{}`, or returns an error if a field without a default was not set.",
+        enum_name, variant_name,
+    );
+
+    let setters = context.fields.iter().map(|field| {
+        let binding = &field.binding;
+        let ty = context.argument_type(field);
+        let value = context.argument_value(field);
+        let docs = format!("Sets the `{}` field.", binding);
+
+        quote!(
+            #[doc = #docs]
+            #[inline]
+            pub fn #binding(mut self, #binding: #ty) -> Self {
+                self.#binding = ::core::option::Option::Some(#value);
+                self
+            }
+        )
+    });
+
+    let value = context.construct(|field| {
+        let binding = &field.binding;
+        match &field.default {
+            Some(default) => quote!(self.#binding.unwrap_or_else(|| #default)),
+            None => quote!(
+                match self.#binding {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => {
+                        return ::core::result::Result::Err(#missing_field_err_type::new(
+                            stringify!(#variant_name),
+                            stringify!(#binding),
+                        ))
+                    }
+                }
+            ),
+        }
+    });
+
+    quote!(
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            #(#setters)*
+
+            #[doc = #build_docs]
+            #[inline]
+            pub fn build(self) -> ::core::result::Result<#enum_path #ty_generics, #missing_field_err_type> {
+                ::core::result::Result::Ok(#value)
+            }
+        }
+    )
+}
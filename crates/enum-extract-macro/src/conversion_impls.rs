@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Fields, Generics, Type, Variant};
+
+use crate::{attributes::VariantAttributes, ErrorTypes};
+
+/// Returns the `From` and `TryFrom` impls between the enum and the payload types of its variants.
+///
+/// Only variants with exactly one field are converted,
+/// and each payload type may only be converted for a single variant.
+/// Payload types are compared as written, so the same type written in two ways is not detected here.
+/// Payloads that are a bare type parameter are skipped, because the `TryFrom` impl would break the orphan rules.
+pub fn all_conversion_impls(
+    enum_name: &Ident,
+    generics: &Generics,
+    variants: &[(&Variant, VariantAttributes)],
+    enum_from: bool,
+    error_types: &ErrorTypes,
+) -> syn::Result<TokenStream> {
+    let mut tokens = TokenStream::new();
+    let mut payload_variants: HashMap<String, &Ident> = HashMap::new();
+
+    for (variant, variant_attributes) in variants {
+        if !variant_attributes.from.unwrap_or(enum_from) {
+            continue;
+        }
+
+        if variant.fields.len() != 1 {
+            if variant_attributes.from == Some(true) {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    "`#[extract(from)]` requires a variant with exactly one field",
+                ));
+            }
+            continue;
+        }
+
+        let field = variant.fields.iter().next().expect("no fields on type");
+        if is_bare_type_parameter(&field.ty, generics) {
+            if variant_attributes.from == Some(true) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`#[extract(from)]` cannot convert a payload that is a bare type parameter",
+                ));
+            }
+            continue;
+        }
+
+        let payload = field.ty.to_token_stream().to_string();
+        if let Some(other_variant) = payload_variants.insert(payload, &variant.ident) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!(
+                    "this payload type is also used by `{}::{}`; add `#[extract(no_from)]` to one of the variants",
+                    enum_name, other_variant,
+                ),
+            ));
+        }
+
+        let context = ConversionContext {
+            enum_name,
+            generics,
+            variant,
+            payload: &field.ty,
+            error_details: error_types.error_details(quote!(value), &[&variant.ident]),
+            actual_name: error_types.variant_name(quote!(value)),
+            err_type: &error_types.err_type,
+            err_value_type: &error_types.err_value_type,
+            err_value_type_with_generics: &error_types.err_value_type_with_generics,
+        };
+
+        tokens.extend(conversion_from_payload(&context));
+        tokens.extend(conversion_try_from_enum(&context));
+        tokens.extend(conversion_try_from_enum_ref(&context));
+    }
+
+    Ok(tokens)
+}
+
+/// Returns whether `ty` is one of the type parameters of `generics`,
+/// or a reference or `Box` of one, which are not covered by a local type.
+fn is_bare_type_parameter(ty: &Type, generics: &Generics) -> bool {
+    match ty {
+        Type::Reference(reference) => is_bare_type_parameter(&reference.elem, generics),
+        Type::Paren(paren) => is_bare_type_parameter(&paren.elem, generics),
+        Type::Group(group) => is_bare_type_parameter(&group.elem, generics),
+        Type::Path(path) if path.qself.is_none() => {
+            let Some(segment) = path.path.segments.last() else {
+                return false;
+            };
+            match &segment.arguments {
+                syn::PathArguments::None if path.path.segments.len() == 1 => generics
+                    .type_params()
+                    .any(|param| param.ident == segment.ident),
+                syn::PathArguments::AngleBracketed(arguments) if segment.ident == "Box" => {
+                    match arguments.args.first() {
+                        Some(syn::GenericArgument::Type(inner)) => {
+                            is_bare_type_parameter(inner, generics)
+                        }
+                        _ => false,
+                    }
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+struct ConversionContext<'a> {
+    enum_name: &'a Ident,
+    generics: &'a Generics,
+    variant: &'a Variant,
+    payload: &'a Type,
+    error_details: TokenStream,
+    actual_name: TokenStream,
+    err_type: &'a Type,
+    err_value_type: &'a Type,
+    err_value_type_with_generics: &'a Type,
+}
+
+impl ConversionContext<'_> {
+    /// Returns a pattern or expression for the variant with its field bound to `inner`.
+    fn variant_with_inner(&self) -> TokenStream {
+        let enum_name = self.enum_name;
+        let variant_name = &self.variant.ident;
+
+        match &self.variant.fields {
+            Fields::Named(named) => {
+                let field_name = named.named.first().and_then(|field| field.ident.as_ref());
+                quote!(#enum_name::#variant_name{ #field_name: inner })
+            }
+            _ => quote!(#enum_name::#variant_name(inner)),
+        }
+    }
+}
+
+fn conversion_from_payload(context: &ConversionContext) -> TokenStream {
+    let enum_name = context.enum_name;
+    let payload = context.payload;
+    let (impl_generics, ty_generics, where_clause) = context.generics.split_for_impl();
+    let value = context.variant_with_inner();
+
+    quote!(
+        impl #impl_generics ::core::convert::From<#payload> for #enum_name #ty_generics #where_clause {
+            #[inline]
+            fn from(inner: #payload) -> Self {
+                #value
+            }
+        }
+    )
+}
+
+fn conversion_try_from_enum(context: &ConversionContext) -> TokenStream {
+    let enum_name = context.enum_name;
+    let variant_name = &context.variant.ident;
+    let payload = context.payload;
+    let (impl_generics, ty_generics, where_clause) = context.generics.split_for_impl();
+    let pattern = context.variant_with_inner();
+    let err_type = context.err_type;
+    let err_value_type = context.err_value_type;
+    let err_value_type_with_generics = context.err_value_type_with_generics;
+    let error_details = &context.error_details;
+    let actual_name = &context.actual_name;
+
+    quote!(
+        impl #impl_generics ::core::convert::TryFrom<#enum_name #ty_generics> for #payload #where_clause {
+            type Error = #err_value_type_with_generics;
+
+            #[inline]
+            #[track_caller]
+            fn try_from(value: #enum_name #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                #[allow(unreachable_patterns)]
+                match value {
+                    #pattern => ::core::result::Result::Ok(inner),
+                    _ => {
+                        let error = #err_type::new(
+                            stringify!(#variant_name),
+                            #actual_name,
+                        )#error_details;
+                        ::core::result::Result::Err(#err_value_type::from_plain_error(error, value))
+                    }
+                }
+            }
+        }
+    )
+}
+
+fn conversion_try_from_enum_ref(context: &ConversionContext) -> TokenStream {
+    let enum_name = context.enum_name;
+    let variant_name = &context.variant.ident;
+    let payload = context.payload;
+    let ref_generics = crate::generics_with_extract_lifetime(context.generics);
+    let (impl_generics, _, where_clause) = ref_generics.split_for_impl();
+    let (_, ty_generics, _) = context.generics.split_for_impl();
+    let pattern = context.variant_with_inner();
+    let err_type = context.err_type;
+    let error_details = &context.error_details;
+    let actual_name = &context.actual_name;
+
+    quote!(
+        impl #impl_generics ::core::convert::TryFrom<&'extract #enum_name #ty_generics> for &'extract #payload #where_clause {
+            type Error = #err_type;
+
+            #[inline]
+            #[track_caller]
+            fn try_from(value: &'extract #enum_name #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                #[allow(unreachable_patterns)]
+                match value {
+                    #pattern => ::core::result::Result::Ok(inner),
+                    _ => {
+                        ::core::result::Result::Err(#err_type::new(
+                            stringify!(#variant_name),
+                            #actual_name,
+                        )#error_details)
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,355 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    DataStruct, DeriveInput, Fields, Generics, Member, Token, Type,
+};
+
+use crate::{
+    attributes::FieldAttributes, function_def::FunctionDef, nested_functions::marker_module_path,
+};
+
+/// Returns the implementations of `Extract` and `VariantName` for a struct that delegates to its enum field,
+/// along with forwarding functions for every variant of the enum, or only those listed in `#[extract(delegate(A, B))]`.
+/// Returns nothing if no field has `#[extract(delegate)]`.
+///
+/// Errors from `into_[variant]` functions hold the whole struct, rebuilt with the enum that failed to extract.
+pub fn impl_all_delegate_fns(ast: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream> {
+    let Some((context, variants)) = delegate_context(ast, data)? else {
+        return Ok(TokenStream::new());
+    };
+
+    // without a list, the enum's `variants!` macro passes its variants back to `__delegate` along with the struct
+    let functions = if variants.is_empty() {
+        let field_type = context.field_type;
+        check_local_path(field_type)?;
+        let module_path = marker_module_path(field_type)?;
+        quote_spanned!(field_type.span()=> #module_path::variants! { #ast })
+    } else {
+        all_forwarding_fns(&context, &variants)?
+    };
+
+    let extract_impl = delegate_extract_impl(&context);
+    let variant_name_impl = delegate_variant_name_impl(&context);
+
+    Ok(quote!(
+        #functions
+
+        #extract_impl
+
+        #variant_name_impl
+    ))
+}
+
+/// Returns an error if the enum field's type may be from another crate,
+/// since the `variants!` macro of an enum can only be used in its own crate.
+///
+/// The check is syntactic: a type is local if it is a single name, or its path starts with `crate`, `self` or `super`.
+fn check_local_path(field_type: &Type) -> syn::Result<()> {
+    let local = match field_type {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let path = &type_path.path;
+            path.leading_colon.is_none()
+                && (path.segments.len() == 1
+                    || ["crate", "self", "super"]
+                        .iter()
+                        .any(|name| path.segments[0].ident == name))
+        }
+        _ => false,
+    };
+
+    if local {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(
+            field_type,
+            "a bare `#[extract(delegate)]` only supports enums from the same crate, named with a single name or a `crate::`, `self::` or `super::` path; \
+            list the variants to forward instead, ex: `#[extract(delegate(A, B))]`",
+        ))
+    }
+}
+
+/// The input of `__delegate`, ex: `[A, B] struct Wrapper(#[extract(delegate)] Inner);`.
+pub struct DelegateInput {
+    variants: Punctuated<Ident, Token![,]>,
+    ast: DeriveInput,
+}
+
+impl Parse for DelegateInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::bracketed!(content in input);
+
+        Ok(DelegateInput {
+            variants: Punctuated::parse_terminated(&content)?,
+            ast: input.parse()?,
+        })
+    }
+}
+
+/// Returns the forwarding functions for every variant in `input`, for a struct with a bare `#[extract(delegate)]`.
+pub fn impl_forwarding_fns(input: &DelegateInput) -> syn::Result<TokenStream> {
+    let syn::Data::Struct(data) = &input.ast.data else {
+        return Err(syn::Error::new_spanned(&input.ast, "expected a struct"));
+    };
+    let Some((context, _)) = delegate_context(&input.ast, data)? else {
+        return Err(syn::Error::new_spanned(
+            &input.ast.ident,
+            "expected a field with `#[extract(delegate)]`",
+        ));
+    };
+    let variants: Vec<Ident> = input.variants.iter().cloned().collect();
+
+    all_forwarding_fns(&context, &variants)
+}
+
+/// Returns the struct's field with `#[extract(delegate)]`, along with the variants listed in it,
+/// or `None` if no field has it.
+fn delegate_context<'a>(
+    ast: &'a DeriveInput,
+    data: &'a DataStruct,
+) -> syn::Result<Option<(DelegateContext<'a>, Vec<Ident>)>> {
+    let mut delegate = None;
+    for (i, field) in data.fields.iter().enumerate() {
+        let Some(variants) = FieldAttributes::parse(&field.attrs)?.delegate else {
+            continue;
+        };
+        if delegate.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "only one field can have `#[extract(delegate)]`",
+            ));
+        }
+
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        };
+        delegate = Some((member, &field.ty, variants));
+    }
+    let Some((member, field_type, variants)) = delegate else {
+        return Ok(None);
+    };
+
+    let context = DelegateContext {
+        struct_name: &ast.ident,
+        generics: &ast.generics,
+        fields: &data.fields,
+        member,
+        field_type,
+    };
+
+    Ok(Some((context, variants)))
+}
+
+/// Returns an impl block with the forwarding functions for each of `variants`.
+fn all_forwarding_fns(context: &DelegateContext, variants: &[Ident]) -> syn::Result<TokenStream> {
+    let struct_name = context.struct_name;
+    let module_path = marker_module_path(context.field_type)?;
+    let functions = variants.iter().map(|variant_name| {
+        delegate_variant_functions(context, &quote!(#module_path::#variant_name), variant_name)
+    });
+    let (impl_generics, ty_generics, where_clause) = context.generics.split_for_impl();
+
+    Ok(quote!(
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #(#functions)*
+        }
+    ))
+}
+
+struct DelegateContext<'a> {
+    struct_name: &'a Ident,
+    generics: &'a Generics,
+    fields: &'a Fields,
+    /// The field holding the enum.
+    member: Member,
+    field_type: &'a Type,
+}
+
+impl DelegateContext<'_> {
+    /// Returns a pattern that moves every field of the struct into a binding, and an expression that rebuilds the struct
+    /// with the enum field replaced by `error.value`.
+    fn destructure(&self) -> (TokenStream, TokenStream) {
+        let bindings: Vec<Ident> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match &field.ident {
+                Some(ident) => ident.clone(),
+                None => Ident::new(&format!("field_{}", i), Span::call_site()),
+            })
+            .collect();
+        let values = self
+            .fields
+            .iter()
+            .zip(&bindings)
+            .enumerate()
+            .map(|(i, (field, binding))| {
+                let is_delegate = match (&self.member, &field.ident) {
+                    (Member::Named(member), Some(ident)) => member == ident,
+                    (Member::Unnamed(member), None) => member.index as usize == i,
+                    _ => false,
+                };
+                if is_delegate {
+                    quote!(error.value)
+                } else {
+                    quote!(#binding)
+                }
+            });
+
+        match self.fields {
+            Fields::Named(_) => (
+                quote!(Self { #(#bindings),* }),
+                quote!(Self { #(#bindings: #values),* }),
+            ),
+            _ => (quote!(Self(#(#bindings),*)), quote!(Self(#(#values),*))),
+        }
+    }
+
+    /// Returns the binding that the enum field is moved into by [`DelegateContext::destructure`].
+    fn delegate_binding(&self) -> Ident {
+        match &self.member {
+            Member::Named(ident) => ident.clone(),
+            Member::Unnamed(index) => {
+                Ident::new(&format!("field_{}", index.index), Span::call_site())
+            }
+        }
+    }
+}
+
+/// Returns the `is_[variant]`, `as_[variant]`, `as_[variant]_mut` and `into_[variant]` functions for one variant of the enum field,
+/// where `marker` is the variant's marker type.
+fn delegate_variant_functions(
+    context: &DelegateContext,
+    marker: &TokenStream,
+    variant_name: &Ident,
+) -> TokenStream {
+    let struct_name = context.struct_name;
+    let member = &context.member;
+    let field_type = context.field_type;
+    let extract = quote!(<#field_type as enum_extract_error::Extract<#marker>>);
+    let (pattern, rebuild) = context.destructure();
+    let binding = context.delegate_binding();
+
+    let is_function = FunctionDef::new_is_delegate(struct_name, variant_name);
+    let as_function = FunctionDef::new_as_delegate(struct_name, variant_name);
+    let as_mut_function = FunctionDef::new_as_delegate_mut(struct_name, variant_name);
+    let into_function = FunctionDef::new_into_delegate(struct_name, variant_name);
+    let is_docs = &is_function.docs;
+    let as_docs = &as_function.docs;
+    let as_mut_docs = &as_mut_function.docs;
+    let into_docs = &into_function.docs;
+
+    quote!(
+        #[doc = #is_docs]
+        #[inline]
+        pub fn #is_function(&self) -> bool {
+            #extract::is_variant(&self.#member)
+        }
+
+        #[doc = #as_docs]
+        #[inline]
+        #[track_caller]
+        pub fn #as_function(&self) -> ::core::result::Result<#extract::Ref<'_>, enum_extract_error::EnumExtractError> {
+            #extract::as_variant(&self.#member)
+        }
+
+        #[doc = #as_mut_docs]
+        #[inline]
+        #[track_caller]
+        pub fn #as_mut_function(&mut self) -> ::core::result::Result<#extract::Mut<'_>, enum_extract_error::EnumExtractError> {
+            #extract::as_variant_mut(&mut self.#member)
+        }
+
+        #[doc = #into_docs]
+        #[inline]
+        #[track_caller]
+        pub fn #into_function(self) -> ::core::result::Result<#extract::Owned, enum_extract_error::EnumExtractValueError<Self>> {
+            let #pattern = self;
+            #extract::into_variant(#binding).map_err(|error| {
+                enum_extract_error::EnumExtractValueError::from_plain_error(error.source, #rebuild)
+            })
+        }
+    )
+}
+
+/// Returns the struct's implementation of `Extract` for every marker type that the enum field implements it for.
+fn delegate_extract_impl(context: &DelegateContext) -> TokenStream {
+    let struct_name = context.struct_name;
+    let member = &context.member;
+    let field_type = context.field_type;
+    let (pattern, rebuild) = context.destructure();
+    let binding = context.delegate_binding();
+
+    let mut generics = context.generics.clone();
+    generics.params.push(syn::parse_quote!(__Marker));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_type: enum_extract_error::Extract<__Marker>));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = context.generics.split_for_impl();
+    let extract = quote!(<#field_type as enum_extract_error::Extract<__Marker>>);
+
+    quote!(
+        impl #impl_generics enum_extract_error::Extract<__Marker> for #struct_name #ty_generics #where_clause {
+            type Ref<'extract> = #extract::Ref<'extract> where Self: 'extract;
+            type Mut<'extract> = #extract::Mut<'extract> where Self: 'extract;
+            type Owned = #extract::Owned;
+
+            fn is_variant(&self) -> bool {
+                #extract::is_variant(&self.#member)
+            }
+
+            #[track_caller]
+            fn as_variant(&self) -> ::core::result::Result<#extract::Ref<'_>, enum_extract_error::EnumExtractError> {
+                #extract::as_variant(&self.#member)
+            }
+
+            #[track_caller]
+            fn as_variant_mut(&mut self) -> ::core::result::Result<#extract::Mut<'_>, enum_extract_error::EnumExtractError> {
+                #extract::as_variant_mut(&mut self.#member)
+            }
+
+            #[track_caller]
+            fn into_variant(self) -> ::core::result::Result<#extract::Owned, enum_extract_error::EnumExtractValueError<Self>> {
+                let #pattern = self;
+                #extract::into_variant(#binding).map_err(|error| {
+                    enum_extract_error::EnumExtractValueError::from_plain_error(error.source, #rebuild)
+                })
+            }
+        }
+    )
+}
+
+/// Returns the struct's implementation of `VariantName`, which names the variant of the enum field.
+fn delegate_variant_name_impl(context: &DelegateContext) -> TokenStream {
+    let struct_name = context.struct_name;
+    let member = &context.member;
+    let field_type = context.field_type;
+    let variant_name_trait = quote!(<#field_type as enum_extract_error::VariantName>);
+
+    let mut generics = context.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#field_type: enum_extract_error::VariantName));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics enum_extract_error::VariantName for #struct_name #ty_generics #where_clause {
+            const VARIANT_NAMES: &'static [&'static str] = #variant_name_trait::VARIANT_NAMES;
+
+            fn variant_name(&self) -> &'static str {
+                #variant_name_trait::variant_name(&self.#member)
+            }
+
+            fn variant_display_name(&self) -> &'static str {
+                #variant_name_trait::variant_display_name(&self.#member)
+            }
+        }
+    )
+}
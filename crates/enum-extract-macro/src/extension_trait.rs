@@ -0,0 +1,79 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Attribute, Generics, ImplItem, Visibility};
+
+/// Returns a trait declaring the functions of `items`, which would otherwise belong in the enum's impl block,
+/// along with its implementation for `self_type`.
+///
+/// Documentation moves to the declarations, and every other attribute stays on the implementations.
+/// Functions that take `self` by value or have no receiver require `Self: Sized`,
+/// so the remaining functions can still be called on a trait object.
+pub fn extension_trait(
+    trait_name: &Ident,
+    visibility: &Visibility,
+    generics: &Generics,
+    self_type: &TokenStream,
+    docs: &str,
+    items: TokenStream,
+) -> syn::Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let inherent_impl: syn::ItemImpl = syn::parse2(quote!(impl #self_type { #items }))?;
+
+    let mut declarations = TokenStream::new();
+    let mut implementations = TokenStream::new();
+    for item in inherent_impl.items {
+        match item {
+            ImplItem::Fn(function) => {
+                let (docs, attrs) = split_docs(function.attrs);
+                let mut signature = function.sig;
+                let block = function.block;
+                implementations.extend(quote!(#(#attrs)* #signature #block));
+
+                let by_value = match signature.receiver() {
+                    Some(receiver) => receiver.reference.is_none(),
+                    None => true,
+                };
+                if by_value {
+                    signature
+                        .generics
+                        .make_where_clause()
+                        .predicates
+                        .push(syn::parse_quote!(Self: ::core::marker::Sized));
+                }
+                declarations.extend(quote!(#(#docs)* #signature;));
+            }
+            ImplItem::Const(constant) => {
+                let (docs, attrs) = split_docs(constant.attrs);
+                let ident = &constant.ident;
+                let ty = &constant.ty;
+                let expr = &constant.expr;
+                declarations.extend(quote!(#(#docs)* const #ident: #ty;));
+                implementations.extend(quote!(#(#attrs)* const #ident: #ty = #expr;));
+            }
+            item => {
+                return Err(syn::Error::new_spanned(
+                    item,
+                    "unsupported item in extension trait",
+                ))
+            }
+        }
+    }
+
+    Ok(quote!(
+        #[doc = #docs]
+        #visibility trait #trait_name #impl_generics #where_clause {
+            #declarations
+        }
+
+        impl #impl_generics #trait_name #ty_generics for #self_type #where_clause {
+            #implementations
+        }
+    ))
+}
+
+/// Splits the documentation from the other attributes.
+fn split_docs(attrs: Vec<Attribute>) -> (Vec<Attribute>, Vec<Attribute>) {
+    attrs
+        .into_iter()
+        .partition(|attr| attr.path().is_ident("doc"))
+}
@@ -0,0 +1,187 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Fields, GenericArgument, PathArguments, PathSegment, Type};
+
+use crate::attributes::FieldAttributes;
+
+/// How the accessor functions return a single field of a variant.
+pub struct FieldAccess<'a> {
+    /// The name the field is bound to in the match pattern.
+    pub binding: Ident,
+    pub ty: &'a Type,
+    /// Whether the field is returned through `Deref`, set with `#[extract(deref)]`.
+    pub deref: bool,
+    /// Whether the field is returned through `DerefMut` by `as_[variant]_mut`, set with `#[extract(deref_mut)]`.
+    pub deref_mut: bool,
+}
+
+impl FieldAccess<'_> {
+    /// ex: `&T` or `&<T as Deref>::Target`, with the `'extract` lifetime if `lifetime` is set
+    fn ref_type(&self, lifetime: bool) -> TokenStream {
+        let ty = self.ty;
+        let lifetime = lifetime.then(|| quote!('extract));
+        if self.deref {
+            quote!(&#lifetime <#ty as ::core::ops::Deref>::Target)
+        } else {
+            quote!(&#lifetime #ty)
+        }
+    }
+
+    fn ref_value(&self) -> TokenStream {
+        let binding = &self.binding;
+        if self.deref {
+            quote!(::core::ops::Deref::deref(#binding))
+        } else {
+            quote!(#binding)
+        }
+    }
+
+    /// ex: `&mut T` or `&mut <T as Deref>::Target`, with the `'extract` lifetime if `lifetime` is set
+    ///
+    /// Many `Deref` types do not implement `DerefMut`, such as `Rc<T>` or `Cow<'_, T>`,
+    /// so the field is only dereferenced if it is marked with `#[extract(deref_mut)]`.
+    fn mut_ref_type(&self, lifetime: bool) -> TokenStream {
+        let ty = self.ty;
+        let lifetime = lifetime.then(|| quote!('extract));
+        if self.deref_mut {
+            quote!(&#lifetime mut <#ty as ::core::ops::Deref>::Target)
+        } else {
+            quote!(&#lifetime mut #ty)
+        }
+    }
+
+    fn mut_ref_value(&self) -> TokenStream {
+        let binding = &self.binding;
+        if self.deref_mut {
+            // spanned so that a type that is not `DerefMut` is reported at the field
+            let ty = self.ty;
+            quote_spanned!(ty.span()=> <#ty as ::core::ops::DerefMut>::deref_mut(#binding))
+        } else {
+            quote!(#binding)
+        }
+    }
+
+    /// ex: `T`, or `U` for a dereferenced `Box<U>`
+    fn val_type(&self) -> TokenStream {
+        match self.boxed_type() {
+            Some(inner) => quote!(#inner),
+            None => {
+                let ty = self.ty;
+                quote!(#ty)
+            }
+        }
+    }
+
+    fn val_value(&self) -> TokenStream {
+        let binding = &self.binding;
+        match self.boxed_type() {
+            Some(_) => quote!(*#binding),
+            None => quote!(#binding),
+        }
+    }
+
+    /// Returns the type inside the `Box` if the field is dereferenced and is a `Box<T>`.
+    ///
+    /// Unsized types such as `Box<str>` cannot be moved out of the `Box`, so they are returned as is.
+    fn boxed_type(&self) -> Option<&Type> {
+        if !self.deref {
+            return None;
+        }
+
+        let segment = last_segment(self.ty).filter(|segment| segment.ident == "Box")?;
+        match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+                match arguments.args.first() {
+                    Some(GenericArgument::Type(inner)) if !is_unsized(inner) => Some(inner),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns whether the type is known to be unsized, ex: `str`, `[T]` or `dyn Trait`.
+fn is_unsized(ty: &Type) -> bool {
+    match ty {
+        Type::Slice(_) | Type::TraitObject(_) => true,
+        Type::Path(path) => path.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+/// Returns the last segment of a type's path, ex: `Rc<T>` for `std::rc::Rc<T>`.
+fn last_segment(ty: &Type) -> Option<&PathSegment> {
+    match ty {
+        Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+/// The return types and values of the accessor functions for all fields of a variant.
+///
+/// A single field is returned on its own, and multiple fields are returned as a tuple.
+pub struct FieldReturns {
+    pub ref_type: TokenStream,
+    pub mut_ref_type: TokenStream,
+    /// The same as `ref_type`, with the `'extract` lifetime.
+    pub extract_ref_type: TokenStream,
+    /// The same as `mut_ref_type`, with the `'extract` lifetime.
+    pub extract_mut_ref_type: TokenStream,
+    pub val_type: TokenStream,
+    pub ref_value: TokenStream,
+    pub mut_ref_value: TokenStream,
+    pub val_value: TokenStream,
+}
+
+impl FieldReturns {
+    pub fn new(fields: &[FieldAccess]) -> Self {
+        let combine = |parts: Vec<TokenStream>| match parts.as_slice() {
+            [part] => part.clone(),
+            _ => quote!((#(#parts,)*)),
+        };
+
+        Self {
+            ref_type: combine(fields.iter().map(|field| field.ref_type(false)).collect()),
+            mut_ref_type: combine(
+                fields
+                    .iter()
+                    .map(|field| field.mut_ref_type(false))
+                    .collect(),
+            ),
+            extract_ref_type: combine(fields.iter().map(|field| field.ref_type(true)).collect()),
+            extract_mut_ref_type: combine(
+                fields
+                    .iter()
+                    .map(|field| field.mut_ref_type(true))
+                    .collect(),
+            ),
+            val_type: combine(fields.iter().map(FieldAccess::val_type).collect()),
+            ref_value: combine(fields.iter().map(FieldAccess::ref_value).collect()),
+            mut_ref_value: combine(fields.iter().map(FieldAccess::mut_ref_value).collect()),
+            val_value: combine(fields.iter().map(FieldAccess::val_value).collect()),
+        }
+    }
+}
+
+impl FieldReturns {
+    /// Returns the return types of a variant's fields, for items that do not bind the fields.
+    pub fn for_variant(
+        fields: &Fields,
+        variant_deref: bool,
+        variant_deref_mut: bool,
+    ) -> syn::Result<Self> {
+        let mut accesses = Vec::new();
+        for (i, field) in fields.iter().enumerate() {
+            let field_attributes = FieldAttributes::parse(&field.attrs)?;
+            accesses.push(FieldAccess {
+                binding: Ident::new(&format!("match_{}", i), Span::call_site()),
+                ty: &field.ty,
+                deref: variant_deref || field_attributes.deref,
+                deref_mut: variant_deref_mut || field_attributes.deref_mut,
+            });
+        }
+
+        Ok(Self::new(&accesses))
+    }
+}
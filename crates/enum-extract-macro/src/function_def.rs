@@ -2,7 +2,7 @@ use heck::ToSnakeCase;
 use proc_macro2::{Ident, Span};
 use quote::ToTokens;
 
-static DOCS_ERROR_TYPE: &'static str = "enum_extract_error::EnumExtractError";
+static DOCS_ERROR_TYPE: &str = "enum_extract_error::EnumExtractError";
 
 pub struct FunctionDef {
     pub declaration: syn::Ident,
@@ -144,4 +144,188 @@ impl FunctionDef {
             ),
         }
     }
+
+    pub fn new_is_group(enum_name: &Ident, group_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns true if this is one of the variants in the `{}` group of `{}`, otherwise false",
+                group_name, enum_name,
+            ),
+            declaration: Ident::new(
+                &format!("is_{}", group_name).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_as_group(enum_name: &Ident, group_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns references to the inner fields as a `{}Ref` if this is one of the variants in the `{}` group of `{}`, otherwise an [`{}`]",
+                group_name, group_name, enum_name, DOCS_ERROR_TYPE,
+            ),
+            declaration: Ident::new(
+                &format!("as_{}", group_name).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_into_group(enum_name: &Ident, group_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns the inner fields as a `{}` if this is one of the variants in the `{}` group of `{}`, otherwise an [`{}`]",
+                group_name, group_name, enum_name, DOCS_ERROR_TYPE,
+            ),
+            declaration: Ident::new(
+                &format!("into_{}", group_name).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_constructor(enum_name: &Ident, variant_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!("Constructs a new `{}::{}`", enum_name, variant_name),
+            declaration: Ident::new(
+                &format!("new_{}", variant_name).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_builder(enum_name: &Ident, variant_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns a builder that constructs a new `{}::{}` one field at a time",
+                enum_name, variant_name,
+            ),
+            declaration: Ident::new(
+                &format!("{}_builder", variant_name).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_copied_variant(enum_name: &Ident, variant_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns copies of the inner fields if this is a `{}::{}`, otherwise an [`{}`]",
+                enum_name, variant_name, DOCS_ERROR_TYPE,
+            ),
+            declaration: Ident::new(
+                &format!("copied_{}", variant_name).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_cloned_variant(enum_name: &Ident, variant_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns clones of the inner fields if this is a `{}::{}`, otherwise an [`{}`]",
+                enum_name, variant_name, DOCS_ERROR_TYPE,
+            ),
+            declaration: Ident::new(
+                &format!("cloned_{}", variant_name).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_is_nested(enum_name: &Ident, variant_name: &Ident, inner_variant: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns true if this is a `{}::{}` holding a `{}`, otherwise false",
+                enum_name, variant_name, inner_variant,
+            ),
+            declaration: Ident::new(
+                &format!("is_{}_{}", variant_name, inner_variant).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_as_nested(enum_name: &Ident, variant_name: &Ident, inner_variant: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns references to the inner fields if this is a `{}::{}` holding a `{}`, otherwise an [`{}`]",
+                enum_name, variant_name, inner_variant, DOCS_ERROR_TYPE,
+            ),
+            declaration: Ident::new(
+                &format!("as_{}_{}", variant_name, inner_variant).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_as_nested_mut(
+        enum_name: &Ident,
+        variant_name: &Ident,
+        inner_variant: &Ident,
+    ) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns mutable references to the inner fields if this is a `{}::{}` holding a `{}`, otherwise an [`{}`]",
+                enum_name, variant_name, inner_variant, DOCS_ERROR_TYPE,
+            ),
+            declaration: Ident::new(
+                &format!("as_{}_{}_mut", variant_name, inner_variant).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_into_nested(enum_name: &Ident, variant_name: &Ident, inner_variant: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns the inner fields if this is a `{}::{}` holding a `{}`, otherwise an [`enum_extract_error::EnumExtractValueError`]",
+                enum_name, variant_name, inner_variant,
+            ),
+            declaration: Ident::new(
+                &format!("into_{}_{}", variant_name, inner_variant).to_snake_case(),
+                Span::call_site(),
+            ),
+        }
+    }
+
+    pub fn new_is_delegate(struct_name: &Ident, variant_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns true if the enum in this `{}` is a `{}`, otherwise false",
+                struct_name, variant_name,
+            ),
+            ..Self::new_is_variant(struct_name, variant_name)
+        }
+    }
+
+    pub fn new_as_delegate(struct_name: &Ident, variant_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns references to the inner fields if the enum in this `{}` is a `{}`, otherwise an [`{}`]",
+                struct_name, variant_name, DOCS_ERROR_TYPE,
+            ),
+            ..Self::new_as_variant(struct_name, variant_name)
+        }
+    }
+
+    pub fn new_as_delegate_mut(struct_name: &Ident, variant_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns mutable references to the inner fields if the enum in this `{}` is a `{}`, otherwise an [`{}`]",
+                struct_name, variant_name, DOCS_ERROR_TYPE,
+            ),
+            ..Self::new_as_variant_mut(struct_name, variant_name)
+        }
+    }
+
+    pub fn new_into_delegate(struct_name: &Ident, variant_name: &Ident) -> Self {
+        FunctionDef {
+            docs: format!(
+                "Returns the inner fields if the enum in this `{}` is a `{}`, otherwise an [`enum_extract_error::EnumExtractValueError`] holding `self`",
+                struct_name, variant_name,
+            ),
+            ..Self::new_into_variant(struct_name, variant_name)
+        }
+    }
 }
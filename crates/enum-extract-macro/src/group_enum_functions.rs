@@ -0,0 +1,432 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use syn::{Attribute, DataEnum, Fields, GenericParam, Generics, Type, Variant, Visibility};
+
+use crate::{attributes::VariantGroup, function_def::FunctionDef, ErrorTypes};
+
+/// Returns the functions for a group, which belong in the enum's impl block.
+pub fn all_group_functions(
+    context: &GroupEnumFunctionContext,
+    error_types: &ErrorTypes,
+) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    tokens.append_all(group_enum_is_group(context));
+    tokens.append_all(group_enum_as_group(context, error_types));
+    tokens.append_all(group_enum_into_group(context, error_types));
+
+    tokens
+}
+
+/// Returns the sub-enums and conversions for a group, which belong next to the enum.
+pub fn all_group_items(
+    context: &GroupEnumFunctionContext,
+    error_types: &ErrorTypes,
+) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    tokens.append_all(group_enum_owned_enum(context));
+    tokens.append_all(group_enum_ref_enum(context));
+    tokens.append_all(group_enum_from_impls(
+        context,
+        &error_types.err_value_type_with_generics,
+    ));
+
+    tokens
+}
+
+pub struct GroupEnumFunctionContext<'a> {
+    pub enum_name: &'a Ident,
+    /// The path of the enum's type, which is the enum's own name unless it is a remote enum.
+    pub enum_path: &'a syn::Path,
+    pub visibility: &'a Visibility,
+    pub generics: &'a Generics,
+    pub group_name: &'a Ident,
+    /// The generics of the enum that the group's variants use.
+    pub group_generics: Generics,
+    pub ref_name: Ident,
+    pub ref_generics: Generics,
+    /// The traits derived for the group's enums, set with `#[extract(group_derive(...))]`.
+    pub derives: &'a [syn::Path],
+    pub variants: Vec<&'a Variant>,
+    pub variant_names: Vec<String>,
+}
+
+impl<'a> GroupEnumFunctionContext<'a> {
+    pub fn new(
+        enum_name: &'a Ident,
+        enum_path: &'a syn::Path,
+        visibility: &'a Visibility,
+        generics: &'a Generics,
+        data: &'a DataEnum,
+        group: &'a VariantGroup,
+        derives: &'a [syn::Path],
+    ) -> syn::Result<Self> {
+        if group.variants.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &group.name,
+                "a group must contain at least one variant",
+            ));
+        }
+
+        let mut variants = Vec::new();
+        for variant_name in &group.variants {
+            let variant = data
+                .variants
+                .iter()
+                .find(|variant| &variant.ident == variant_name)
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        variant_name,
+                        format!("`{}` is not a variant of `{}`", variant_name, enum_name),
+                    )
+                })?;
+            variants.push(variant);
+        }
+
+        let group_generics = used_generics(generics, &variants);
+        // A group of unit variants holds no references, so its reference enum has no lifetime.
+        let ref_generics = if variants.iter().all(|variant| variant.fields.is_empty()) {
+            group_generics.clone()
+        } else {
+            crate::generics_with_extract_lifetime(&group_generics)
+        };
+
+        Ok(Self {
+            enum_name,
+            enum_path,
+            visibility,
+            generics,
+            group_name: &group.name,
+            group_generics,
+            ref_name: format_ident!("{}Ref", group.name),
+            ref_generics,
+            derives,
+            variant_names: variants.iter().map(|v| v.ident.to_string()).collect(),
+            variants,
+        })
+    }
+
+    /// Returns the calls that add user-facing wording and the debug output to the group's errors.
+    fn error_details(&self, error_types: &ErrorTypes) -> TokenStream {
+        let variants: Vec<&Ident> = self.variants.iter().map(|variant| &variant.ident).collect();
+        error_types.error_details(quote!(self), &variants)
+    }
+}
+
+/// Returns the generic parameters of `generics` that the fields of `variants` use,
+/// along with the parameters that their bounds refer to and the where predicates that apply to them.
+///
+/// The check is syntactic: a parameter is used if its name appears anywhere in a field's type.
+fn used_generics(generics: &Generics, variants: &[&Variant]) -> Generics {
+    let mut used = HashSet::new();
+    for field in variants.iter().flat_map(|variant| &variant.fields) {
+        crate::collect_names(field.ty.to_token_stream(), &mut used);
+    }
+
+    loop {
+        let count = used.len();
+        for param in &generics.params {
+            if used.contains(&param_name(param)) {
+                crate::collect_names(param.to_token_stream(), &mut used);
+            }
+        }
+        if used.len() == count {
+            break;
+        }
+    }
+
+    let all: HashSet<String> = generics.params.iter().map(param_name).collect();
+    let mut result = generics.clone();
+    result.params = generics
+        .params
+        .iter()
+        .filter(|param| used.contains(&param_name(param)))
+        .cloned()
+        .collect();
+    if let Some(where_clause) = &mut result.where_clause {
+        where_clause.predicates = where_clause
+            .predicates
+            .iter()
+            .filter(|predicate| {
+                let mut names = HashSet::new();
+                crate::collect_names(predicate.to_token_stream(), &mut names);
+                names.intersection(&all).all(|name| used.contains(name))
+            })
+            .cloned()
+            .collect();
+    }
+
+    result
+}
+
+/// Returns the name of a generic parameter, with a leading `'` for lifetimes.
+fn param_name(param: &GenericParam) -> String {
+    match param {
+        GenericParam::Type(param) => param.ident.to_string(),
+        GenericParam::Lifetime(param) => param.lifetime.to_string(),
+        GenericParam::Const(param) => param.ident.to_string(),
+    }
+}
+
+/// Returns the names to bind each field of the variant to.
+fn field_bindings(fields: &Fields) -> Vec<Ident> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => Ident::new(&format!("match_{}", i), Span::call_site()),
+        })
+        .collect()
+}
+
+/// Returns a pattern or expression for the variant with each field bound to its binding.
+fn variant_with_bindings(path: TokenStream, fields: &Fields) -> TokenStream {
+    let bindings = field_bindings(fields);
+
+    match fields {
+        Fields::Unit => path,
+        Fields::Unnamed(_) => quote!(#path( #(#bindings),* )),
+        Fields::Named(_) => quote!(#path{ #(#bindings),* }),
+    }
+}
+
+/// Returns a variant declaration, with each field type transformed by `map_type`.
+fn variant_declaration(variant: &Variant, map_type: impl Fn(&Type) -> TokenStream) -> TokenStream {
+    let variant_name = &variant.ident;
+    let docs = doc_attributes(&variant.attrs);
+
+    let fields = variant.fields.iter().map(|field| {
+        let field_docs = doc_attributes(&field.attrs);
+        let ty = map_type(&field.ty);
+
+        match &field.ident {
+            Some(ident) => quote!(#(#field_docs)* #ident: #ty),
+            None => quote!(#ty),
+        }
+    });
+
+    match &variant.fields {
+        Fields::Unit => quote!(#(#docs)* #variant_name),
+        Fields::Unnamed(_) => quote!(#(#docs)* #variant_name( #(#fields),* )),
+        Fields::Named(_) => quote!(#(#docs)* #variant_name{ #(#fields),* }),
+    }
+}
+
+fn doc_attributes(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .collect()
+}
+
+fn group_enum_is_group(context: &GroupEnumFunctionContext) -> TokenStream {
+    let function_def = FunctionDef::new_is_group(context.enum_name, context.group_name);
+    let function_name = function_def.declaration;
+    let docs = function_def.docs;
+
+    let patterns = context.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => quote!(Self::#variant_name),
+            Fields::Unnamed(_) => quote!(Self::#variant_name(..)),
+            Fields::Named(_) => quote!(Self::#variant_name{ .. }),
+        }
+    });
+
+    quote!(
+        #[doc = #docs]
+        #[inline]
+        pub fn #function_name(&self) -> bool {
+            matches!(self, #(#patterns)|*)
+        }
+    )
+}
+
+fn group_enum_as_group(
+    context: &GroupEnumFunctionContext,
+    error_types: &ErrorTypes,
+) -> TokenStream {
+    let function_def = FunctionDef::new_as_group(context.enum_name, context.group_name);
+    let function_name = function_def.declaration;
+    let docs = function_def.docs;
+    let ref_name = &context.ref_name;
+    let (_, ref_ty_generics, _) = context.ref_generics.split_for_impl();
+    let variant_names = &context.variant_names;
+    let err_type = &error_types.err_type;
+    let error_details = context.error_details(error_types);
+    let actual_name = error_types.variant_name(quote!(self));
+
+    let arms = context.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant_with_bindings(quote!(Self::#variant_name), &variant.fields);
+        let value = variant_with_bindings(quote!(#ref_name::#variant_name), &variant.fields);
+
+        quote!(#pattern => ::core::result::Result::Ok(#value),)
+    });
+
+    quote!(
+        #[doc = #docs ]
+        #[inline]
+        #[track_caller]
+        pub fn #function_name<'extract>(&'extract self) -> ::core::result::Result<#ref_name #ref_ty_generics, #err_type> {
+            #[allow(unreachable_patterns)]
+            match self {
+                #(#arms)*
+                _ => {
+                    ::core::result::Result::Err(#err_type::new_one_of(
+                        [#(#variant_names),*],
+                        #actual_name,
+                    )#error_details)
+                }
+            }
+        }
+    )
+}
+
+fn group_enum_into_group(
+    context: &GroupEnumFunctionContext,
+    error_types: &ErrorTypes,
+) -> TokenStream {
+    let function_def = FunctionDef::new_into_group(context.enum_name, context.group_name);
+    let function_name = function_def.declaration;
+    let docs = function_def.docs;
+    let group_name = context.group_name;
+    let (_, ty_generics, _) = context.group_generics.split_for_impl();
+    let variant_names = &context.variant_names;
+    let err_type = &error_types.err_type;
+    let err_value_type = &error_types.err_value_type;
+    let err_value_type_with_generics = &error_types.err_value_type_with_generics;
+    let error_details = context.error_details(error_types);
+    let actual_name = error_types.variant_name(quote!(self));
+
+    let arms = context.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant_with_bindings(quote!(Self::#variant_name), &variant.fields);
+        let value = variant_with_bindings(quote!(#group_name::#variant_name), &variant.fields);
+
+        quote!(#pattern => ::core::result::Result::Ok(#value),)
+    });
+
+    quote!(
+        #[doc = #docs ]
+        #[inline]
+        #[track_caller]
+        pub fn #function_name(self) -> ::core::result::Result<#group_name #ty_generics, #err_value_type_with_generics> {
+            #[allow(unreachable_patterns)]
+            match self {
+                #(#arms)*
+                _ => {
+                    let error = #err_type::new_one_of(
+                        [#(#variant_names),*],
+                        #actual_name,
+                    )#error_details;
+                    ::core::result::Result::Err(#err_value_type::from_plain_error(error, self))
+                }
+            }
+        }
+    )
+}
+
+fn group_enum_owned_enum(context: &GroupEnumFunctionContext) -> TokenStream {
+    let visibility = context.visibility;
+    let group_name = context.group_name;
+    let (impl_generics, _, where_clause) = context.group_generics.split_for_impl();
+    let derives = derive_attribute(context.derives);
+    let docs = format!(
+        "The variants in the `{}` group of [`{}`], returned by [`{}::{}`].",
+        group_name,
+        context.enum_name,
+        context.enum_name,
+        FunctionDef::new_into_group(context.enum_name, group_name).declaration,
+    );
+
+    let variants = context
+        .variants
+        .iter()
+        .map(|variant| variant_declaration(variant, |ty| quote!(#ty)));
+
+    quote!(
+        #[doc = #docs]
+        #derives
+        #visibility enum #group_name #impl_generics #where_clause {
+            #(#variants),*
+        }
+    )
+}
+
+fn group_enum_ref_enum(context: &GroupEnumFunctionContext) -> TokenStream {
+    let visibility = context.visibility;
+    let ref_name = &context.ref_name;
+    let (impl_generics, _, where_clause) = context.ref_generics.split_for_impl();
+    let derives = derive_attribute(context.derives);
+    let docs = format!(
+        "References to the variants in the `{}` group of [`{}`], returned by [`{}::{}`].",
+        context.group_name,
+        context.enum_name,
+        context.enum_name,
+        FunctionDef::new_as_group(context.enum_name, context.group_name).declaration,
+    );
+
+    let variants = context
+        .variants
+        .iter()
+        .map(|variant| variant_declaration(variant, |ty| quote!(&'extract #ty)));
+
+    quote!(
+        #[doc = #docs]
+        #derives
+        #visibility enum #ref_name #impl_generics #where_clause {
+            #(#variants),*
+        }
+    )
+}
+
+fn derive_attribute(derives: &[syn::Path]) -> TokenStream {
+    if derives.is_empty() {
+        TokenStream::new()
+    } else {
+        quote!(#[derive(#(#derives),*)])
+    }
+}
+
+fn group_enum_from_impls(
+    context: &GroupEnumFunctionContext,
+    err_value_type_with_generics: &Type,
+) -> TokenStream {
+    let enum_name = context.enum_name;
+    let enum_path = context.enum_path;
+    let group_name = context.group_name;
+    let into_function = FunctionDef::new_into_group(enum_name, group_name).declaration;
+    let (impl_generics, ty_generics, where_clause) = context.generics.split_for_impl();
+    let (_, group_ty_generics, _) = context.group_generics.split_for_impl();
+
+    let arms = context.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant_with_bindings(quote!(#group_name::#variant_name), &variant.fields);
+        let value = variant_with_bindings(quote!(Self::#variant_name), &variant.fields);
+
+        quote!(#pattern => #value,)
+    });
+
+    quote!(
+        impl #impl_generics ::core::convert::From<#group_name #group_ty_generics> for #enum_path #ty_generics #where_clause {
+            #[inline]
+            fn from(value: #group_name #group_ty_generics) -> Self {
+                match value {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<#enum_path #ty_generics> for #group_name #group_ty_generics #where_clause {
+            type Error = #err_value_type_with_generics;
+
+            #[inline]
+            fn try_from(value: #enum_path #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                value.#into_function()
+            }
+        }
+    )
+}
@@ -0,0 +1,112 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Fields, Generics, Variant, Visibility};
+
+use crate::ErrorTypes;
+
+/// Returns the kind enum of the enum, along with its implementation of `EnumExtract`.
+///
+/// The kind enum is named `[Enum]Kind` unless renamed with `#[extract(kind = Name)]`.
+/// Errors from `EnumExtract::mismatch` use the same display names and messages as the enum's own functions.
+pub fn all_kind_items(
+    enum_name: &Ident,
+    visibility: &Visibility,
+    generics: &Generics,
+    variants: &[&Variant],
+    kind_name: Option<&Ident>,
+    error_types: &ErrorTypes,
+) -> TokenStream {
+    let kind_name = kind_name
+        .cloned()
+        .unwrap_or_else(|| format_ident!("{}Kind", enum_name));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let docs = format!(
+        "The kinds of variants of [`{}`], returned by `EnumExtract::kind`.",
+        enum_name
+    );
+
+    let variant_names: Vec<&Ident> = variants.iter().map(|variant| &variant.ident).collect();
+    let variant_docs = variants.iter().map(|variant| {
+        variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .collect::<Vec<_>>()
+    });
+    // the enum is named instead of `Self`, since a variant named `Kind` would be ambiguous
+    let kind_arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => quote!(#enum_name::#variant_name => #kind_name::#variant_name,),
+            Fields::Unnamed(_) => {
+                quote!(#enum_name::#variant_name(..) => #kind_name::#variant_name,)
+            }
+            Fields::Named(_) => quote!(#enum_name::#variant_name{..} => #kind_name::#variant_name,),
+        }
+    });
+    let display_names = variants
+        .iter()
+        .map(|variant| error_types.display_name(&variant.ident));
+    let err_type = &error_types.err_type;
+    let actual_name = error_types.variant_name(quote!(self));
+    let mismatch_arms = variant_names.iter().map(|variant_name| {
+        let error_details = error_types.error_details(quote!(self), &[variant_name]);
+        quote!(
+            #kind_name::#variant_name => {
+                #err_type::new(stringify!(#variant_name), #actual_name)#error_details
+            }
+        )
+    });
+
+    quote!(
+        #[doc = #docs]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        // the variants are named after the enum's variants
+        #[allow(non_camel_case_types)]
+        #visibility enum #kind_name {
+            #(
+                #(#variant_docs)*
+                #variant_names,
+            )*
+        }
+
+        impl #kind_name {
+            /// Every kind, in declaration order.
+            pub const ALL: &'static [Self] = &[#(Self::#variant_names),*];
+        }
+
+        impl enum_extract_error::VariantName for #kind_name {
+            const VARIANT_NAMES: &'static [&'static str] = &[#(stringify!(#variant_names)),*];
+
+            fn variant_name(&self) -> &'static str {
+                match *self {
+                    #(Self::#variant_names => stringify!(#variant_names),)*
+                }
+            }
+
+            fn variant_display_name(&self) -> &'static str {
+                match *self {
+                    #(Self::#variant_names => #display_names,)*
+                }
+            }
+        }
+
+        impl #impl_generics enum_extract_error::EnumExtract for #enum_name #ty_generics #where_clause {
+            type Kind = #kind_name;
+
+            fn kind(&self) -> #kind_name {
+                match self {
+                    #(#kind_arms)*
+                    _ => unreachable!(),
+                }
+            }
+
+            #[track_caller]
+            fn mismatch(&self, expected: #kind_name) -> #err_type {
+                match expected {
+                    #(#mismatch_arms)*
+                }
+            }
+        }
+    )
+}
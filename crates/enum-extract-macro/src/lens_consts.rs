@@ -0,0 +1,48 @@
+use heck::ToShoutySnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{ext::IdentExt, DataStruct, DeriveInput, Fields};
+
+/// Returns the `FieldLens` constants for the struct's fields, ex: `Outer::BODY` for `Outer::body`,
+/// which compose with the `Prism` constants of enums.
+///
+/// Only structs with named fields have lenses, and each lens has the visibility of its field.
+pub fn all_lens_consts(ast: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream> {
+    let struct_name = &ast.ident;
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            struct_name,
+            "`#[extract(lenses)]` requires a struct with named fields",
+        ));
+    };
+
+    let consts = fields.named.iter().map(|field| {
+        let field_name = field.ident.as_ref().expect("expected a named field");
+        let name = field_name.unraw().to_string();
+        let const_name = Ident::new(&name.to_shouty_snake_case(), Span::call_site());
+        let visibility = &field.vis;
+        let field_type = &field.ty;
+        let docs = format!(
+            "A lens focused on the `{}` field of [`{}`].",
+            name, struct_name
+        );
+
+        quote!(
+            #[doc = #docs]
+            #visibility const #const_name: enum_extract_error::FieldLens<Self, #field_type> =
+                enum_extract_error::FieldLens::new(
+                    #name,
+                    |source| &source.#field_name,
+                    |source| &mut source.#field_name,
+                    |source| source.#field_name,
+                );
+        )
+    });
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    Ok(quote!(
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #(#consts)*
+        }
+    ))
+}
@@ -28,6 +28,19 @@
 //! Since these functions can panic they are not recommended for production code.
 //! Their main use is in tests, in which they can simplify and flatten tests significantly.
 //!
+//! ## Generated Items
+//!
+//! Besides the functions, the derive adds the following items next to the enum.
+//! Items that are not always generated are only added when the enum asks for them, so they cannot collide with the user's own items.
+//!
+//! - `impl enum_extract_error::VariantName for Enum`, always.
+//! - `[Enum]Kind`, the kind enum, with `#[extract(kind)]`, or named `Name` with `#[extract(kind = Name)]`.
+//! - `__enum_extract_[enum]`, a hidden module of marker types named after the enum in snake case, always.
+//! - `[enum]`, the public module of marker types, with `#[extract(markers)]`, or named `name` with `#[extract(markers = name)]`.
+//! - `Enum::[VARIANT]`, the prism constants for every variant with exactly one field, with `#[extract(prisms)]`.
+//! - `Name` and `NameRef`, the group enums, for every group declared with `#[extract(group(Name = [...]))]`.
+//! - `[Enum][Variant]Builder`, the builder of a variant, with `#[extract(builder)]`.
+//!
 //! # Examples
 //!
 //! ## Unit Variants
@@ -316,20 +329,493 @@
 //! // panics with a decent message
 //! let one = named.extract_as_two();
 //! ```
+//!
+//! ## Groups
+//!
+//! Variants can be grouped with `#[extract(group(Name = [Variant, ...]))]`,
+//! which generates a `Name` enum holding the grouped variants, a `NameRef` enum holding references to them,
+//! and `is_[group]`, `as_[group]` and `into_[group]` functions.
+//! `From` and `TryFrom` are implemented between the enum and the group's enum.
+//!
+//! The group enums have the generic parameters of the original enum that their variants use.
+//! `#[extract(group_derive(Trait, ...))]` on the enum derives the given traits for every group enum.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, EnumExtract)]
+//! #[extract(group(Numeric = [Int, Float]))]
+//! enum Value {
+//!     Int(i64),
+//!     Float(f64),
+//!     Text(String),
+//! }
+//!
+//! let value = Value::Int(1);
+//! assert!(value.is_numeric());
+//! assert!(matches!(value.as_numeric(), Ok(NumericRef::Int(&1))));
+//!
+//! let error = Value::Text("one".to_string()).as_numeric().err().unwrap();
+//! assert_eq!(error.to_string(), "expected one of [Int, Float], got Text");
+//! ```
+//!
+//! ## Conversions
+//!
+//! `#[extract(from)]` on the enum implements `From<T>` for the enum, and `TryFrom` from the enum (and references to it) into `T`,
+//! for every variant with a single field of type `T`.
+//! Variants can opt in individually with `#[extract(from)]`, or opt out with `#[extract(no_from)]`.
+//! Each payload type may only be converted for one variant.
+//! Payload types are compared as they are written, so a type written in two ways (such as `Vec<u8>` and `std::vec::Vec<u8>`, or through an alias)
+//! is not caught by the derive and fails with conflicting impls instead; mark all but one of those variants with `#[extract(no_from)]`.
+//! A payload that is a bare type parameter of the enum, such as `T`, `&T` or `Box<T>`, is skipped,
+//! because the `TryFrom` impl into it is not allowed by the orphan rules.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, PartialEq, EnumExtract)]
+//! #[extract(from)]
+//! enum Value {
+//!     Int(i64),
+//!     Text(String),
+//! }
+//!
+//! assert_eq!(Value::from(1_i64), Value::Int(1));
+//!
+//! let text: Result<String, _> = Value::Text("one".to_string()).try_into();
+//! assert_eq!(text.unwrap(), "one");
+//! ```
+//!
+//! ## Constructors
+//!
+//! `#[extract(new)]` on the enum or a variant generates a `new_[variant]` function, taking each field as an argument.
+//! Fields marked with `#[extract(default)]` or `#[extract(default = expr)]` are left out of the arguments and use the default instead.
+//! `#[extract(into)]` on the enum or a variant makes the arguments `impl Into<T>`,
+//! and `#[extract(builder)]` generates a `[variant]_builder` function for named variants that sets one field at a time.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, PartialEq, EnumExtract)]
+//! #[extract(new, into)]
+//! enum Shape {
+//!     Circle(f64),
+//!     #[extract(builder)]
+//!     Rectangle {
+//!         width: f64,
+//!         height: f64,
+//!         #[extract(default = "unnamed".to_string())]
+//!         name: String,
+//!     },
+//! }
+//!
+//! assert_eq!(Shape::new_circle(1.0), Shape::Circle(1.0));
+//!
+//! let rectangle = Shape::rectangle_builder().width(1.0).height(2.0).build().unwrap();
+//! assert_eq!(rectangle, Shape::new_rectangle(1.0, 2.0));
+//! ```
+//!
+//! ## Smart Pointers
+//!
+//! `#[extract(deref)]` on a field, or on a variant for all of its fields, returns the field through `Deref`.
+//! `as_[variant]` returns `&<T as Deref>::Target`, so a `Box<Node>` is returned as `&Node` and a `String` as `&str`.
+//! `as_[variant]_mut` still returns `&mut T`, since many `Deref` types such as `Rc<T>` and `Cow<'_, T>` do not implement `DerefMut`.
+//! `#[extract(deref_mut)]` returns `&mut <T as Deref>::Target` from it instead, for types that do.
+//! `into_[variant]` moves the value out of a `Box<T>` and returns `T`.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, PartialEq, EnumExtract)]
+//! enum Node {
+//!     Leaf(#[extract(deref_mut)] String),
+//!     Wrapper(#[extract(deref)] Box<Node>),
+//! }
+//!
+//! let mut node = Node::Leaf("leaf".to_string());
+//! node.as_leaf_mut().unwrap().make_ascii_uppercase();
+//! assert_eq!(node.as_leaf().unwrap(), "LEAF");
+//!
+//! let node = Node::Wrapper(Box::new(Node::Leaf("leaf".to_string())));
+//! assert_eq!(node.as_wrapper().unwrap().as_leaf().unwrap(), "leaf");
+//! assert_eq!(node.into_wrapper().unwrap(), Node::Leaf("leaf".to_string()));
+//! ```
+//!
+//! ## Copies and Clones
+//!
+//! `#[extract(copy)]` on the enum or a variant generates `copied_[variant]` functions,
+//! which return the fields by value.
+//! `#[extract(clone)]` generates `cloned_[variant]` functions, which clone the fields instead.
+//! The fields of every variant the functions are generated for must be `Copy` or `Clone` respectively.
+//! Fields whose types mention a generic parameter are bounded on the function instead, so it can only be called when they are.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, EnumExtract)]
+//! enum Value {
+//!     #[extract(copy)]
+//!     Point(i32, i32),
+//!     #[extract(clone)]
+//!     Text { text: String },
+//! }
+//!
+//! assert_eq!(Value::Point(1, 2).copied_point().unwrap(), (1, 2));
+//!
+//! let value = Value::Text { text: "text".to_string() };
+//! assert_eq!(value.cloned_text().unwrap(), "text");
+//! ```
+//!
+//! ## Variant Names
+//!
+//! The derive implements `enum_extract_error::VariantName`,
+//! so generic code can ask any derived enum for the name of its variant or list the names of all variants.
+//!
+//! ```rust
+//! use enum_extract_error::VariantName;
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, EnumExtract)]
+//! enum Shape {
+//!     Circle(f64),
+//!     Square(f64),
+//! }
+//!
+//! assert_eq!(Shape::Square(1.0).variant_name(), "Square");
+//! assert_eq!(Shape::VARIANT_NAMES, ["Circle", "Square"]);
+//! ```
+//!
+//! ## Kinds
+//!
+//! `#[extract(kind)]` on the enum implements `enum_extract_error::EnumExtract`, for code that is generic over derived enums.
+//! It generates a fieldless `[Enum]Kind` enum with one variant for each variant of the enum,
+//! which can be renamed with `#[extract(kind = Name)]`.
+//!
+//! ```rust
+//! use enum_extract_error::EnumExtract;
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, EnumExtract)]
+//! #[extract(kind)]
+//! enum Shape {
+//!     Circle(f64),
+//!     Square(f64),
+//! }
+//!
+//! let shape = Shape::Square(1.0);
+//! assert_eq!(shape.kind(), ShapeKind::Square);
+//! assert_eq!(Shape::VARIANT_COUNT, 2);
+//! assert_eq!(
+//!     shape.mismatch(ShapeKind::Circle).to_string(),
+//!     "expected Circle, got Square"
+//! );
+//! ```
+//!
+//! ## Marker Types
+//!
+//! `#[extract(markers)]` on the enum generates a module named after the enum in snake case, which can be renamed with `#[extract(markers = name)]`.
+//! It holds a zero-sized marker type for each variant, used with `enum_extract_error::Extract`
+//! so generic code can extract a variant without knowing the name of its functions.
+//!
+//! ```rust
+//! use enum_extract_error::{EnumExtractError, Extract, ExtractExt};
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, EnumExtract)]
+//! #[extract(markers)]
+//! enum Shape {
+//!     Circle(f64),
+//!     Square(f64),
+//! }
+//!
+//! fn require<V, E: Extract<V>>(value: &E) -> Result<E::Ref<'_>, EnumExtractError> {
+//!     value.extract_ref::<V>()
+//! }
+//!
+//! let shape = Shape::Square(1.0);
+//! assert!(shape.is::<shape::Square>());
+//! assert_eq!(require::<shape::Square, _>(&shape).unwrap(), &1.0);
+//! assert!(require::<shape::Circle, _>(&shape).is_err());
+//! ```
+//!
+//! ## Prisms
+//!
+//! `#[extract(prisms)]` on the enum gives every variant with exactly one field a `Prism` constant named after it in screaming snake case, ex: `Shape::CIRCLE`.
+//! Variants without fields, or with several, have no prism, since a prism focuses on a single value.
+//!
+//! `#[extract(lenses)]` on a struct with named fields gives every field a `FieldLens` constant named after it, ex: `Drawing::SHAPE`,
+//! with the same visibility as the field.
+//! Like a struct that delegates its functions, the struct cannot implement `Drop`, since a lens moves the field out of it.
+//!
+//! Prisms and lenses are values, so they can be stored and composed with `then`.
+//! When a composed prism fails, the error's path records the steps that succeeded before it.
+//!
+//! ```rust
+//! use enum_extract_error::{Optic, Prism};
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, PartialEq, EnumExtract)]
+//! #[extract(prisms)]
+//! enum Shape {
+//!     Circle(f64),
+//!     Square(f64),
+//! }
+//!
+//! #[derive(EnumExtract)]
+//! #[extract(lenses)]
+//! struct Drawing {
+//!     shape: Shape,
+//! }
+//!
+//! let radius = Drawing::SHAPE.then(Shape::CIRCLE);
+//!
+//! let drawing = Drawing { shape: Shape::CIRCLE.review(1.0) };
+//! assert_eq!(radius.preview(&drawing).unwrap(), &1.0);
+//!
+//! let drawing = Drawing { shape: Shape::Square(1.0) };
+//! assert_eq!(
+//!     radius.preview(&drawing).unwrap_err().to_string(),
+//!     "at shape: expected Circle, got Square"
+//! );
+//! ```
+//!
+//! ## Nested Enums
+//!
+//! A variant that holds another enum deriving `EnumExtract` can extract the inner enum's variants in one call
+//! with `#[extract(nested(A, B))]`, which generates `is_[variant]_[inner]`, `as_[variant]_[inner]`,
+//! `as_[variant]_[inner]_mut` and `into_[variant]_[inner]` for each listed variant.
+//! The inner variants must be listed, and errors name both enums.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, EnumExtract)]
+//! enum Shape {
+//!     Circle(f64),
+//!     Square(f64),
+//! }
+//!
+//! #[derive(Debug, EnumExtract)]
+//! enum Item {
+//!     #[extract(nested(Circle, Square))]
+//!     Shape(Shape),
+//!     Text(String),
+//! }
+//!
+//! let item = Item::Shape(Shape::Circle(1.0));
+//! assert_eq!(item.as_shape_circle().unwrap(), &1.0);
+//! assert_eq!(
+//!     item.as_shape_square().unwrap_err().to_string(),
+//!     "expected Item::Shape::Square, got Item::Shape::Circle"
+//! );
+//!
+//! let item = Item::Text("text".to_string());
+//! assert_eq!(
+//!     item.as_shape_circle().unwrap_err().to_string(),
+//!     "expected Item::Shape, got Item::Text"
+//! );
+//! ```
+//!
+//! ## Delegating Structs
+//!
+//! A struct that wraps an enum can derive `EnumExtract` with `#[extract(delegate)]` on the enum field,
+//! which generates `is_[variant]`, `as_[variant]`, `as_[variant]_mut` and `into_[variant]` for every variant of the enum,
+//! and implements `enum_extract_error::Extract` and `enum_extract_error::VariantName` for the struct by forwarding to the field.
+//! Listing variants, as in `#[extract(delegate(A, B))]`, generates the functions for only those variants.
+//!
+//! Errors from `into_[variant]` hold the whole struct, so nothing is lost when extraction fails.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, PartialEq, EnumExtract)]
+//! enum Body {
+//!     Json(String),
+//!     Text(String),
+//! }
+//!
+//! #[derive(Debug, PartialEq, EnumExtract)]
+//! struct Message {
+//!     id: u32,
+//!     #[extract(delegate)]
+//!     body: Body,
+//! }
+//!
+//! let message = Message { id: 1, body: Body::Text("hello".to_string()) };
+//! assert_eq!(message.as_text().unwrap(), "hello");
+//!
+//! let error = message.into_json().unwrap_err();
+//! assert_eq!(error.to_string(), "expected Json, got Text");
+//! assert_eq!(error.value.id, 1);
+//! ```
+//!
+//! A bare `#[extract(delegate)]` finds the variants through a macro generated with the enum, which can only be used in the enum's own crate.
+//! So the field's type must be a single name, or a path starting with `crate`, `self` or `super`.
+//! An enum from another crate, or named through another path, needs its variants listed instead.
+//!
+//! ```rust,compile_fail
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(EnumExtract)]
+//! struct Comparison {
+//!     // error: list the variants to forward instead, ex: `#[extract(delegate(A, B))]`
+//!     #[extract(delegate)]
+//!     ordering: std::cmp::Ordering,
+//! }
+//! ```
+//!
+//! The struct cannot implement `Drop`, since `into_[variant]` and `Extract::into_variant` move the enum out of it.
+//!
+//! ```rust,compile_fail,E0509
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(EnumExtract)]
+//! enum State {
+//!     Locked(u32),
+//!     Unlocked,
+//! }
+//!
+//! #[derive(EnumExtract)]
+//! struct Guard(#[extract(delegate(Locked))] State);
+//!
+//! // error[E0509]: cannot move out of type `Guard`, which implements the `Drop` trait
+//! impl Drop for Guard {
+//!     fn drop(&mut self) {}
+//! }
+//! ```
+//!
+//! ## Extension Traits
+//!
+//! With `#[extract(trait = "Name")]`, the functions are declared in a trait with that name and implemented for the enum, instead of in an inherent impl block.
+//! Users then import the trait to call them, and can implement it for their own types.
+//! Functions that take the enum by value or construct it require `Self: Sized`, so the trait can be used as a trait object.
+//!
+//! Prisms stay on the enum itself.
+//!
+//! ```rust
+//! mod shapes {
+//!     use enum_extract_macro::EnumExtract;
+//!
+//!     #[derive(Debug, EnumExtract)]
+//!     #[extract(trait = "ShapeExt")]
+//!     pub enum Shape {
+//!         Circle(f64),
+//!         Square(f64),
+//!     }
+//! }
+//!
+//! use shapes::{Shape, ShapeExt};
+//!
+//! let shape: &dyn ShapeExt = &Shape::Circle(1.0);
+//! assert_eq!(shape.as_circle().unwrap(), &1.0);
+//! ```
+//!
+//! ## Remote Enums
+//!
+//! An enum from another crate can be derived through a mirror of its definition with `#[extract(remote = "other::Enum")]`, like serde's remote derive.
+//! Since the functions cannot be added to a type from another crate, they are put in an extension trait named after the mirror, ex: `OrderingDefExt`, unless renamed with `#[extract(trait = "Name")]`.
+//! A variant that is missing from the mirror, such as a new variant of a `#[non_exhaustive]` enum, is named `"<unknown>"` in errors.
+//!
+//! `kind`, `markers`, `from` and `prisms` are not supported for remote enums, and `enum_extract_error::VariantName` is not implemented.
+//!
+//! ```rust
+//! use std::cmp::Ordering;
+//!
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(EnumExtract)]
+//! #[extract(remote = "std::cmp::Ordering")]
+//! enum OrderingDef {
+//!     Less,
+//!     Equal,
+//!     Greater,
+//! }
+//!
+//! let ordering = 1.cmp(&2);
+//! assert!(ordering.is_less());
+//! ```
+//!
+//! ## Error Messages
+//!
+//! `#[extract(display = "...")]` on a variant sets the user-facing name used for it in error messages.
+//! `#[extract(message = "...")]` on the enum or a variant replaces the `expected {expected}, got {actual}` wording,
+//! where `{expected}` and `{actual}` are replaced with the user-facing names.
+//! A variant's message is used by the errors of its own functions, and the enum's message by all others.
+//! The variant names are still available from the error's `expected` and `actual` functions.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, EnumExtract)]
+//! enum Source {
+//!     #[extract(
+//!         display = "a file source",
+//!         message = "this command requires {expected}, but {actual} was given"
+//!     )]
+//!     File(String),
+//!     #[extract(display = "a URL source")]
+//!     Url(String),
+//! }
+//!
+//! let source = Source::Url("https://example.com".to_string());
+//! let error = source.as_file().unwrap_err();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "this command requires a file source, but a URL source was given"
+//! );
+//! assert_eq!(error.actual(), "Url");
+//! ```
+//!
+//! ## Debug Output
+//!
+//! With the `alloc` feature of `enum-extract-error` enabled, errors record the `Debug` output of the actual value
+//! if the enum implements `Debug`, available from the error's `actual_debug` function.
+//! `#[extract(redact)]` on a field renders it as `<redacted>`, so secrets are not leaked into logs.
+//! Generic enums whose `Debug` implementation depends on their generic parameters are not recorded.
+//!
+//! ```rust
+//! use enum_extract_macro::EnumExtract;
+//!
+//! #[derive(Debug, EnumExtract)]
+//! enum Credentials {
+//!     Token(#[extract(redact)] String),
+//!     Login { user: String, #[extract(redact)] password: String },
+//! }
+//!
+//! let credentials = Credentials::Token("secret".to_string());
+//! let error = credentials.as_login().unwrap_err();
+//! assert_eq!(error.actual_debug(), Some("Token(<redacted>)"));
+//! ```
 
 #![warn(missing_docs)]
 
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
 use syn::{parse_macro_input, DataEnum, DeriveInput};
 
+mod attributes;
+mod constructor_functions;
+mod conversion_impls;
+mod delegate_functions;
+mod extension_trait;
+mod field_access;
 mod function_def;
+mod group_enum_functions;
+mod kind_enum;
+mod lens_consts;
+mod marker_types;
 mod named_enum_functions;
+mod nested_functions;
+mod prism_consts;
 mod unit_enum_functions;
 mod unnamed_enum_functions;
+mod variant_debug;
 
-/// Derive functions on an Enum for easily accessing individual items in the Enum
-#[proc_macro_derive(EnumExtract, attributes(derive_err))]
+/// Derive functions on an Enum for easily accessing individual items in the Enum,
+/// or on a struct that delegates them to an Enum field or has lenses for its fields
+#[proc_macro_derive(EnumExtract, attributes(derive_err, extract))]
 pub fn enum_extract(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // get a usable token stream
     let ast: DeriveInput = parse_macro_input!(input as DeriveInput);
@@ -337,41 +823,110 @@ pub fn enum_extract(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let name = &ast.ident;
     let generics = &ast.generics;
 
-    let enum_data = if let syn::Data::Enum(data) = &ast.data {
-        data
-    } else {
-        panic!("{} is not an enum", name);
-    };
-
     let mut expanded = TokenStream::new();
 
     // Build the impl
-    let fns = impl_all_as_fns(name, generics, enum_data);
+    let fns = match &ast.data {
+        syn::Data::Enum(enum_data) => {
+            attributes::EnumAttributes::parse(&ast.attrs).and_then(|enum_attributes| {
+                impl_all_as_fns(name, &ast.vis, generics, &enum_attributes, enum_data)
+            })
+        }
+        syn::Data::Struct(struct_data) => impl_all_struct_fns(&ast, struct_data),
+        syn::Data::Union(_) => panic!("{} is not an enum or a struct", name),
+    };
 
-    expanded.extend(fns);
+    match fns {
+        Ok(fns) => expanded.extend(fns),
+        Err(err) => expanded.extend(err.to_compile_error()),
+    }
 
     proc_macro::TokenStream::from(expanded)
 }
 
-/// Returns an impl block for all of the enum's functions.
-fn impl_all_as_fns(enum_name: &Ident, generics: &syn::Generics, data: &DataEnum) -> TokenStream {
+/// Generate the forwarding functions of a struct with a bare `#[extract(delegate)]`,
+/// called by the `variants!` macro of the enum it delegates to with the names of the enum's variants
+#[doc(hidden)]
+#[proc_macro]
+pub fn __delegate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as delegate_functions::DelegateInput);
+
+    match delegate_functions::impl_forwarding_fns(&input) {
+        Ok(fns) => proc_macro::TokenStream::from(fns),
+        Err(err) => proc_macro::TokenStream::from(err.to_compile_error()),
+    }
+}
+
+/// Returns the items of a struct, which are the functions it delegates to its enum field and its lenses.
+fn impl_all_struct_fns(ast: &DeriveInput, data: &syn::DataStruct) -> syn::Result<TokenStream> {
+    let struct_attributes = attributes::StructAttributes::parse(&ast.attrs)?;
+    let delegate = delegate_functions::impl_all_delegate_fns(ast, data)?;
+    if delegate.is_empty() && !struct_attributes.lenses {
+        return Err(syn::Error::new_spanned(
+            &ast.ident,
+            "a struct must have a field with `#[extract(delegate)]`, or `#[extract(lenses)]` on the struct",
+        ));
+    }
+
+    let lenses = if struct_attributes.lenses {
+        lens_consts::all_lens_consts(ast, data)?
+    } else {
+        TokenStream::new()
+    };
+
+    Ok(quote!(
+        #delegate
+
+        #lenses
+    ))
+}
+
+/// Returns an impl block for all of the enum's functions, along with any supporting items.
+fn impl_all_as_fns(
+    enum_name: &Ident,
+    visibility: &syn::Visibility,
+    generics: &syn::Generics,
+    enum_attributes: &attributes::EnumAttributes,
+    data: &DataEnum,
+) -> syn::Result<TokenStream> {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let err_path = syn::Path::from(syn::PathSegment::from(syn::Ident::new(
-        "enum_extract_error",
-        Span::call_site(),
-    )));
-    let err_name = syn::Ident::new("EnumExtractError", Span::call_site());
-    let err_type = get_error_type(&err_name, &err_path);
+    let mut variants = Vec::new();
+    for variant_data in &data.variants {
+        let variant_attributes = attributes::VariantAttributes::parse(&variant_data.attrs)?;
+        variants.push((variant_data, variant_attributes));
+    }
+
+    // a remote enum is another crate's enum, so its functions and variant names are put in an extension trait
+    let remote = enum_attributes.remote.as_ref();
+    if remote.is_some() {
+        check_remote_attributes(enum_attributes, &variants)?;
+    }
+    let enum_path = match remote {
+        Some(path) => path.clone(),
+        None => syn::Path::from(enum_name.clone()),
+    };
+    let extension_trait = match (&enum_attributes.extension_trait, remote) {
+        (Some(trait_name), _) => Some(trait_name.clone()),
+        (None, Some(_)) => Some(format_ident!("{}Ext", enum_name)),
+        (None, None) => None,
+    };
 
-    let err_value_name = syn::Ident::new("EnumExtractValueError", Span::call_site());
-    let err_value_type = get_error_type(&err_value_name, &err_path);
-    let err_value_type_with_generics =
-        get_error_type_with_generics(err_value_name, err_path, enum_name, generics);
+    let error_types = ErrorTypes::new(
+        enum_name,
+        &enum_path,
+        generics,
+        enum_attributes,
+        &variants,
+        remote.is_some(),
+    );
 
     let mut stream = TokenStream::new();
+    let mut consts = TokenStream::new();
+    let mut items = TokenStream::new();
     let mut variant_names = TokenStream::new();
-    for variant_data in &data.variants {
+    let mut variant_display_names = TokenStream::new();
+    for (variant_data, variant_attributes) in &variants {
         let variant_name = &variant_data.ident;
 
         let tokens = match &variant_data.fields {
@@ -379,75 +934,481 @@ fn impl_all_as_fns(enum_name: &Ident, generics: &syn::Generics, data: &DataEnum)
             syn::Fields::Unnamed(unnamed) => unnamed_enum_functions::all_unnamed_functions(
                 enum_name,
                 variant_name,
-                &err_type,
-                &err_value_type,
-                &err_value_type_with_generics,
+                generics,
+                &error_types,
                 unnamed,
-            ),
+                enum_attributes,
+                variant_attributes,
+            )?,
             syn::Fields::Named(named) => named_enum_functions::all_named_functions(
                 enum_name,
                 variant_name,
-                &err_type,
-                &err_value_type,
-                &err_value_type_with_generics,
+                generics,
+                &error_types,
                 named,
-            ),
+                enum_attributes,
+                variant_attributes,
+            )?,
         };
 
         stream.extend(tokens);
+        stream.extend(nested_functions::all_nested_functions(
+            enum_name,
+            variant_data,
+            variant_attributes,
+            &error_types,
+        )?);
+        if enum_attributes.prisms {
+            consts.extend(prism_consts::variant_prism_const(
+                enum_name,
+                variant_data,
+                &error_types,
+            ));
+        }
 
-        let variant_name = match &variant_data.fields {
-            syn::Fields::Unit => quote!(Self::#variant_name => stringify!(#variant_name),),
-            syn::Fields::Unnamed(_) => {
-                quote!(Self::#variant_name(..) => stringify!(#variant_name),)
-            }
-            syn::Fields::Named(_) => quote!(Self::#variant_name{..} => stringify!(#variant_name),),
+        let constructor_context = constructor_functions::ConstructorContext::new(
+            enum_name,
+            &enum_path,
+            visibility,
+            generics,
+            variant_data,
+            enum_attributes,
+            variant_attributes,
+        )?;
+        stream.extend(constructor_functions::all_constructor_functions(
+            &constructor_context,
+        ));
+        items.extend(constructor_functions::all_constructor_items(
+            &constructor_context,
+            &error_types,
+        ));
+
+        let pattern = match &variant_data.fields {
+            syn::Fields::Unit => quote!(Self::#variant_name),
+            syn::Fields::Unnamed(_) => quote!(Self::#variant_name(..)),
+            syn::Fields::Named(_) => quote!(Self::#variant_name{..}),
         };
+        let display_name = error_types.display_name(variant_name);
+
+        variant_names.extend(quote!(#pattern => stringify!(#variant_name),));
+        variant_display_names.extend(quote!(#pattern => #display_name,));
+    }
+
+    for group in &enum_attributes.groups {
+        let context = group_enum_functions::GroupEnumFunctionContext::new(
+            enum_name,
+            &enum_path,
+            visibility,
+            generics,
+            data,
+            group,
+            &enum_attributes.group_derives,
+        )?;
 
-        variant_names.extend(variant_name);
+        stream.extend(group_enum_functions::all_group_functions(
+            &context,
+            &error_types,
+        ));
+        items.extend(group_enum_functions::all_group_items(
+            &context,
+            &error_types,
+        ));
     }
 
-    quote!(
-        impl #impl_generics #enum_name #ty_generics #where_clause {
-            #stream
+    // the kind, marker and conversion impls are all for traits from other crates, so cannot be implemented for a remote enum
+    if remote.is_none() {
+        if enum_attributes.kind {
+            let variant_data: Vec<&syn::Variant> =
+                variants.iter().map(|(variant, _)| *variant).collect();
+            items.extend(kind_enum::all_kind_items(
+                enum_name,
+                visibility,
+                generics,
+                &variant_data,
+                enum_attributes.kind_name.as_ref(),
+                &error_types,
+            ));
+        }
+
+        items.extend(marker_types::all_marker_items(
+            enum_name,
+            visibility,
+            generics,
+            &variants,
+            enum_attributes.markers,
+            enum_attributes.markers_name.as_ref(),
+            &error_types,
+        )?);
+
+        items.extend(conversion_impls::all_conversion_impls(
+            enum_name,
+            generics,
+            &variants,
+            enum_attributes.from,
+            &error_types,
+        )?);
+    }
+
+    // the default returns the variant name, which is only different when a variant has a user-facing name
+    let variant_display_name_fn = if error_types.has_display_names() {
+        quote!(
+            fn variant_display_name(&self) -> &'static str {
+                match self {
+                    #variant_display_names
+                    _ => unreachable!(),
+                }
+            }
+        )
+    } else {
+        TokenStream::new()
+    };
+    let all_variant_names = variants.iter().map(|(variant, _)| &variant.ident);
+
+    let variant_debug_impl =
+        variant_debug::variant_debug_impl(enum_name, &enum_path, generics, &variants)?;
+
+    if let (Some(_), Some(trait_name)) = (remote, &extension_trait) {
+        let docs = format!(
+            "Functions of the remote enum mirrored by [`{}`], generated by `#[derive(EnumExtract)]`.",
+            enum_name
+        );
+
+        let extension_trait = extension_trait::extension_trait(
+            trait_name,
+            visibility,
+            generics,
+            &quote!(#enum_path #ty_generics),
+            &docs,
+            stream,
+        )?;
+
+        // the variant names are only used by the errors, so they are kept off the extension trait,
+        // and a remote enum may be `#[non_exhaustive]` or have variants that are missing from the mirror
+        let variant_name_impl = quote!(
+            impl #impl_generics enum_extract_error::__private::RemoteVariantName<#enum_name #ty_generics>
+                for #enum_path #ty_generics #where_clause
+            {
+                const VARIANT_NAMES: &'static [&'static str] = &[#(stringify!(#all_variant_names)),*];
+
+                fn variant_name(&self) -> &'static str {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        #variant_names
+                        _ => "<unknown>",
+                    }
+                }
+
+                fn variant_display_name(&self) -> &'static str {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        #variant_display_names
+                        _ => "<unknown>",
+                    }
+                }
+            }
+        );
+
+        return Ok(quote!(
+            #extension_trait
+
+            #variant_name_impl
+
+            #variant_debug_impl
+
+            #items
+        ));
+    }
+    let functions = match &extension_trait {
+        Some(trait_name) => {
+            let docs = format!(
+                "Functions of [`{}`], generated by `#[derive(EnumExtract)]`.",
+                enum_name
+            );
+            let extension_trait = extension_trait::extension_trait(
+                trait_name,
+                visibility,
+                generics,
+                &quote!(#enum_name #ty_generics),
+                &docs,
+                stream,
+            )?;
+
+            // the prisms are not functions users call on the enum, so they stay in its impl block
+            quote!(
+                #extension_trait
+
+                impl #impl_generics #enum_name #ty_generics #where_clause {
+                    #consts
+                }
+            )
+        }
+        None => quote!(
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #stream
+
+                #consts
+            }
+        ),
+    };
+
+    Ok(quote!(
+        #functions
+
+        #variant_debug_impl
+
+        impl #impl_generics enum_extract_error::VariantName for #enum_name #ty_generics #where_clause {
+            const VARIANT_NAMES: &'static [&'static str] = &[#(stringify!(#all_variant_names)),*];
 
-            /// Returns the name of the variant.
             fn variant_name(&self) -> &'static str {
                 match self {
                     #variant_names
                     _ => unreachable!(),
                 }
             }
+
+            #variant_display_name_fn
         }
-    )
+
+        #items
+    ))
+}
+
+/// The error types used by the generated functions.
+pub(crate) struct ErrorTypes {
+    /// ex: `EnumExtractError`
+    pub err_type: syn::Type,
+    /// ex: `EnumExtractValueError`
+    pub err_value_type: syn::Type,
+    /// ex: `EnumExtractValueError<Enum<T>>`
+    pub err_value_type_with_generics: syn::Type,
+    /// ex: `MissingFieldError`
+    pub missing_field_err_type: syn::Type,
+    /// User-facing names of the variants, set with `#[extract(display = "...")]`.
+    display_names: HashMap<String, String>,
+    /// Message templates of errors that expected a variant, set with `#[extract(message = "...")]` on the variant.
+    messages: HashMap<String, String>,
+    /// Message template of all other errors, set with `#[extract(message = "...")]` on the enum.
+    message: Option<String>,
+    /// ex: `<Enum<T> as VariantName>`, or `<other::Enum<T> as RemoteVariantName<Mirror<T>>>` for a remote enum
+    variant_name_trait: TokenStream,
+    /// ex: `<Enum<T> as VariantDebug<Enum<T>>>`, or `<other::Enum<T> as VariantDebug<Mirror<T>>>` for a remote enum
+    variant_debug_trait: TokenStream,
+}
+
+impl ErrorTypes {
+    fn new(
+        enum_name: &Ident,
+        enum_path: &syn::Path,
+        generics: &syn::Generics,
+        enum_attributes: &attributes::EnumAttributes,
+        variants: &[(&syn::Variant, attributes::VariantAttributes)],
+        remote: bool,
+    ) -> Self {
+        let err_path = syn::Path::from(syn::PathSegment::from(syn::Ident::new(
+            "enum_extract_error",
+            Span::call_site(),
+        )));
+        let err_name = syn::Ident::new("EnumExtractError", Span::call_site());
+        let err_value_name = syn::Ident::new("EnumExtractValueError", Span::call_site());
+        let missing_field_err_name = syn::Ident::new("MissingFieldError", Span::call_site());
+
+        Self {
+            err_type: get_error_type(&err_name, &err_path),
+            err_value_type: get_error_type(&err_value_name, &err_path),
+            missing_field_err_type: get_error_type(&missing_field_err_name, &err_path),
+            err_value_type_with_generics: get_error_type_with_generics(
+                err_value_name,
+                err_path,
+                enum_path,
+                generics,
+            ),
+            display_names: variants
+                .iter()
+                .filter_map(|(variant, attributes)| {
+                    let display = attributes.display.clone()?;
+                    Some((variant.ident.to_string(), display))
+                })
+                .collect(),
+            messages: variants
+                .iter()
+                .filter_map(|(variant, attributes)| {
+                    let message = attributes.message.clone()?;
+                    Some((variant.ident.to_string(), message))
+                })
+                .collect(),
+            message: enum_attributes.message.clone(),
+            variant_name_trait: {
+                let (_, ty_generics, _) = generics.split_for_impl();
+                if remote {
+                    quote!(<#enum_path #ty_generics as enum_extract_error::__private::RemoteVariantName<#enum_name #ty_generics>>)
+                } else {
+                    quote!(<#enum_path #ty_generics as enum_extract_error::VariantName>)
+                }
+            },
+            variant_debug_trait: {
+                let (_, ty_generics, _) = generics.split_for_impl();
+                quote!(<#enum_path #ty_generics as enum_extract_error::__private::VariantDebug<#enum_name #ty_generics>>)
+            },
+        }
+    }
+
+    /// Returns a call to [`VariantName::variant_name`] for `receiver`, which is the enum or a reference to it.
+    ///
+    /// The trait is named explicitly so the call is not shadowed by an inherent function of the same name.
+    pub fn variant_name(&self, receiver: TokenStream) -> TokenStream {
+        let variant_name_trait = &self.variant_name_trait;
+        quote!(#variant_name_trait::variant_name(&#receiver))
+    }
+
+    /// Returns whether any variant has a user-facing name.
+    pub fn has_display_names(&self) -> bool {
+        !self.display_names.is_empty()
+    }
+
+    /// Returns the user-facing name of a variant, which defaults to its name.
+    pub fn display_name(&self, variant: &Ident) -> String {
+        let name = variant.to_string();
+        self.display_names.get(&name).cloned().unwrap_or(name)
+    }
+
+    /// Returns the calls that add user-facing wording and the `Debug` output of `receiver` to an error that expected any of `expected`,
+    /// where `receiver` is the enum that failed to extract.
+    ///
+    /// No wording is added unless the enum or its variants have a display name or message,
+    /// and the `Debug` output is only recorded if the `alloc` feature of `enum-extract-error` is enabled.
+    pub fn error_details(&self, receiver: TokenStream, expected: &[&Ident]) -> TokenStream {
+        let mut tokens = TokenStream::new();
+
+        if self.has_display_names() {
+            let expected_display = match expected {
+                [variant] => self.display_name(variant),
+                _ => {
+                    let names: Vec<String> = expected
+                        .iter()
+                        .map(|variant| self.display_name(variant))
+                        .collect();
+                    format!("one of [{}]", names.join(", "))
+                }
+            };
+            let variant_name_trait = &self.variant_name_trait;
+            tokens.extend(quote!(.with_display(
+                #expected_display,
+                #variant_name_trait::variant_display_name(&#receiver),
+            )));
+        }
+
+        let message = match expected {
+            [variant] => self.messages.get(&variant.to_string()),
+            _ => None,
+        };
+        if let Some(message) = message.or(self.message.as_ref()) {
+            tokens.extend(quote!(.with_message(#message)));
+        }
+
+        let variant_debug_trait = &self.variant_debug_trait;
+        tokens.extend(
+            quote!(.__with_actual_debug(|| #variant_debug_trait::variant_debug(&#receiver))),
+        );
+
+        tokens
+    }
+}
+
+/// Returns an error for the options that a remote enum does not support,
+/// which all add impls or items to the enum's own type.
+fn check_remote_attributes(
+    enum_attributes: &attributes::EnumAttributes,
+    variants: &[(&syn::Variant, attributes::VariantAttributes)],
+) -> syn::Result<()> {
+    let message = |option: &str| {
+        format!(
+            "`#[extract({})]` is not supported for remote enums, since its impls would be for a type from another crate",
+            option
+        )
+    };
+
+    if enum_attributes.kind {
+        return Err(syn::Error::new(Span::call_site(), message("kind")));
+    }
+    if enum_attributes.markers {
+        return Err(syn::Error::new(Span::call_site(), message("markers")));
+    }
+    if enum_attributes.from {
+        return Err(syn::Error::new(Span::call_site(), message("from")));
+    }
+    if enum_attributes.prisms {
+        return Err(syn::Error::new(Span::call_site(), message("prisms")));
+    }
+    for (variant, variant_attributes) in variants {
+        if variant_attributes.from == Some(true) {
+            return Err(syn::Error::new_spanned(&variant.ident, message("from")));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether `ty` mentions one of the type parameters of `generics`.
+///
+/// The check is syntactic: a parameter is mentioned if its name appears anywhere in the type.
+fn mentions_type_parameter(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    let mut names = HashSet::new();
+    collect_names(ty.to_token_stream(), &mut names);
+    generics
+        .type_params()
+        .any(|param| names.contains(&param.ident.to_string()))
+}
+
+/// Adds every identifier and lifetime in `tokens` to `names`, with a leading `'` for lifetimes.
+fn collect_names(tokens: TokenStream, names: &mut HashSet<String>) {
+    let mut lifetime = false;
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => collect_names(group.stream(), names),
+            TokenTree::Ident(ident) if lifetime => {
+                names.insert(format!("'{}", ident));
+            }
+            TokenTree::Ident(ident) => {
+                names.insert(ident.to_string());
+            }
+            TokenTree::Punct(ref punct) if punct.as_char() == '\'' => {
+                lifetime = true;
+                continue;
+            }
+            _ => {}
+        }
+        lifetime = false;
+    }
+}
+
+/// Returns a copy of the generics with an additional `'extract` lifetime,
+/// for items that borrow from the enum.
+fn generics_with_extract_lifetime(generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, syn::parse_quote!('extract));
+    generics
 }
 
 /// Returns the error type. ex: `EnumExtractError`
 fn get_error_type(err_name: &Ident, err_path: &syn::Path) -> syn::Type {
-    let err_type = {
-        let last_segment = syn::PathSegment::from(err_name.clone());
-        let mut path = err_path.clone();
-        path.segments.push(last_segment);
-        syn::Type::Path(syn::TypePath {
-            qself: None,
-            path: path,
-        })
-    };
-    err_type
+    let last_segment = syn::PathSegment::from(err_name.clone());
+    let mut path = err_path.clone();
+    path.segments.push(last_segment);
+    syn::Type::Path(syn::TypePath { qself: None, path })
 }
 
 /// Returns the error type with generics. ex: `EnumExtractError<T>`
 fn get_error_type_with_generics(
     err_name: Ident,
     err_path: syn::Path,
-    enum_name: &Ident,
+    enum_path: &syn::Path,
     generics: &syn::Generics,
 ) -> syn::Type {
     let err_type_with_generics = {
         let mut last_segment = syn::PathSegment::from(err_name.clone());
         let mut path = err_path.clone();
 
-        let mut inner_type_path = syn::Path::from(syn::PathSegment::from(enum_name.clone()));
+        let mut inner_type_path = enum_path.clone();
         let inner_type_segment = inner_type_path.segments.last_mut().unwrap();
         let mut generic_args = syn::punctuated::Punctuated::new();
         for param in generics.params.iter() {
@@ -498,10 +1459,7 @@ fn get_error_type_with_generics(
                 gt_token: syn::token::Gt::default(),
             });
         path.segments.push(last_segment);
-        syn::Type::Path(syn::TypePath {
-            qself: None,
-            path: path,
-        })
+        syn::Type::Path(syn::TypePath { qself: None, path })
     };
     err_type_with_generics
 }
@@ -0,0 +1,244 @@
+use heck::ToSnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Fields, Generics, Variant, Visibility};
+
+use crate::{
+    attributes::VariantAttributes, field_access::FieldReturns, function_def::FunctionDef,
+    ErrorTypes,
+};
+
+/// Returns the module of marker types for the enum's variants, along with the enum's implementations of `Extract`.
+///
+/// The markers are generated in a hidden module, ex: `__enum_extract_many_variants` for `ManyVariants`,
+/// which nested enums and delegating structs refer to, along with a `variants!` macro that lists the variants for delegating structs.
+/// With `#[extract(markers)]` they are generated in a public module named after the enum in snake case instead,
+/// ex: `many_variants`, unless renamed with `#[extract(markers = name)]`, which is imported under the hidden name.
+pub fn all_marker_items(
+    enum_name: &Ident,
+    visibility: &Visibility,
+    generics: &Generics,
+    variants: &[(&Variant, VariantAttributes)],
+    markers: bool,
+    markers_name: Option<&Ident>,
+    error_types: &ErrorTypes,
+) -> syn::Result<TokenStream> {
+    let hidden_name = hidden_module_ident(enum_name);
+    let module_name = match (markers, markers_name) {
+        (true, Some(markers_name)) => markers_name.clone(),
+        (true, None) => snake_case_ident(enum_name),
+        (false, _) => hidden_name.clone(),
+    };
+
+    let marker_structs = variants.iter().map(|(variant, _)| {
+        let variant_name = &variant.ident;
+        let docs = format!(
+            "Marks the [`{}::{}`](super::{}::{}) variant.",
+            enum_name, variant_name, enum_name, variant_name
+        );
+
+        quote!(
+            #[doc = #docs]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            pub struct #variant_name;
+        )
+    });
+
+    // a struct with a bare `#[extract(delegate)]` calls this macro with its own definition,
+    // since it cannot see the enum's variants otherwise
+    let all_variant_names = variants.iter().map(|(variant, _)| &variant.ident);
+    let variants_macro = quote!(
+        #[doc(hidden)]
+        macro_rules! __enum_extract_variants {
+            ($($input:tt)*) => {
+                enum_extract_macro::__delegate! { [#(#all_variant_names),*] $($input)* }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use __enum_extract_variants as variants;
+    );
+
+    let mut impls = TokenStream::new();
+    for (variant, variant_attributes) in variants {
+        impls.extend(extract_impl(
+            enum_name,
+            generics,
+            variant,
+            variant_attributes,
+            &module_name,
+            error_types,
+        )?);
+    }
+
+    // the public module is also reachable under the hidden name, with a `use` rather than a path through `super`,
+    // which would not resolve for an enum declared inside a function
+    let module = if markers {
+        let docs = format!(
+            "Marker types for the variants of [`{}`], used with `enum_extract_error::Extract`.",
+            enum_name
+        );
+
+        quote!(
+            #[doc = #docs]
+            // the markers are only constructed by users of the enum
+            #[allow(dead_code, non_camel_case_types)]
+            #visibility mod #module_name {
+                #(#marker_structs)*
+
+                #variants_macro
+            }
+
+            #[doc(hidden)]
+            #[allow(unused_imports)]
+            #visibility use #module_name as #hidden_name;
+        )
+    } else {
+        quote!(
+            #[doc(hidden)]
+            // the markers are only constructed by users of the enum
+            #[allow(dead_code, non_camel_case_types)]
+            #visibility mod #module_name {
+                #(#marker_structs)*
+
+                #variants_macro
+            }
+        )
+    };
+
+    Ok(quote!(
+        #module
+
+        #impls
+    ))
+}
+
+/// Returns the enum's implementation of `Extract` for one variant,
+/// which calls the variant's own functions.
+fn extract_impl(
+    enum_name: &Ident,
+    generics: &Generics,
+    variant: &Variant,
+    variant_attributes: &VariantAttributes,
+    module_name: &Ident,
+    error_types: &ErrorTypes,
+) -> syn::Result<TokenStream> {
+    let variant_name = &variant.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let err_type = &error_types.err_type;
+    let err_value_type_with_generics = &error_types.err_value_type_with_generics;
+    // the associated types are qualified, since a variant may have the same name
+    let extract_trait = quote!(<Self as enum_extract_error::Extract<#module_name::#variant_name>>);
+
+    let body = match &variant.fields {
+        Fields::Unit => {
+            let err_value_type = &error_types.err_value_type;
+            let error_details = error_types.error_details(quote!(self), &[variant_name]);
+            let actual_name = error_types.variant_name(quote!(self));
+            let error = quote!(
+                #err_type::new(stringify!(#variant_name), #actual_name)#error_details
+            );
+
+            quote!(
+                type Ref<'extract> = () where Self: 'extract;
+                type Mut<'extract> = () where Self: 'extract;
+                type Owned = ();
+
+                fn is_variant(&self) -> bool {
+                    ::core::matches!(self, #enum_name::#variant_name)
+                }
+
+                #[track_caller]
+                fn as_variant(&self) -> ::core::result::Result<(), #err_type> {
+                    match self {
+                        #enum_name::#variant_name => ::core::result::Result::Ok(()),
+                        _ => ::core::result::Result::Err(#error),
+                    }
+                }
+
+                #[track_caller]
+                fn as_variant_mut(&mut self) -> ::core::result::Result<(), #err_type> {
+                    match self {
+                        #enum_name::#variant_name => ::core::result::Result::Ok(()),
+                        _ => ::core::result::Result::Err(#error),
+                    }
+                }
+
+                #[track_caller]
+                fn into_variant(self) -> ::core::result::Result<(), #err_value_type_with_generics> {
+                    match self {
+                        #enum_name::#variant_name => ::core::result::Result::Ok(()),
+                        _ => {
+                            let error = #error;
+                            ::core::result::Result::Err(#err_value_type::from_plain_error(error, self))
+                        }
+                    }
+                }
+            )
+        }
+        fields => {
+            let returns = FieldReturns::for_variant(
+                fields,
+                variant_attributes.deref,
+                variant_attributes.deref_mut,
+            )?;
+            let ref_type = &returns.extract_ref_type;
+            let mut_ref_type = &returns.extract_mut_ref_type;
+            let val_type = &returns.val_type;
+            let is_function = FunctionDef::new_is_variant(enum_name, variant_name).declaration;
+            let as_function = FunctionDef::new_as_variant(enum_name, variant_name).declaration;
+            let as_mut_function =
+                FunctionDef::new_as_variant_mut(enum_name, variant_name).declaration;
+            let into_function = FunctionDef::new_into_variant(enum_name, variant_name).declaration;
+
+            quote!(
+                type Ref<'extract> = #ref_type where Self: 'extract;
+                type Mut<'extract> = #mut_ref_type where Self: 'extract;
+                type Owned = #val_type;
+
+                fn is_variant(&self) -> bool {
+                    Self::#is_function(self)
+                }
+
+                #[track_caller]
+                fn as_variant(&self) -> ::core::result::Result<#extract_trait::Ref<'_>, #err_type> {
+                    Self::#as_function(self)
+                }
+
+                #[track_caller]
+                fn as_variant_mut(&mut self) -> ::core::result::Result<#extract_trait::Mut<'_>, #err_type> {
+                    Self::#as_mut_function(self)
+                }
+
+                #[track_caller]
+                fn into_variant(self) -> ::core::result::Result<#extract_trait::Owned, #err_value_type_with_generics> {
+                    Self::#into_function(self)
+                }
+            )
+        }
+    };
+
+    Ok(quote!(
+        impl #impl_generics enum_extract_error::Extract<#module_name::#variant_name> for #enum_name #ty_generics #where_clause {
+            #body
+        }
+    ))
+}
+
+/// Returns the name of the hidden module of marker types of the enum, ex: `__enum_extract_many_variants` for `ManyVariants`.
+pub fn hidden_module_ident(enum_name: &Ident) -> Ident {
+    format_ident!(
+        "__enum_extract_{}",
+        enum_name.unraw().to_string().to_snake_case()
+    )
+}
+
+/// Returns the snake case name of the enum, as a raw identifier if it is a keyword.
+pub fn snake_case_ident(enum_name: &Ident) -> Ident {
+    let name = enum_name.to_string().to_snake_case();
+    match syn::parse_str::<Ident>(&name) {
+        Ok(ident) => ident,
+        Err(_) => Ident::new_raw(&name, Span::call_site()),
+    }
+}
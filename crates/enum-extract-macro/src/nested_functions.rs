@@ -0,0 +1,149 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Fields, Type, Variant};
+
+use crate::{
+    attributes::VariantAttributes, function_def::FunctionDef, marker_types::hidden_module_ident,
+    ErrorTypes,
+};
+
+/// Returns the functions that extract a variant of the inner enum through the variant that holds it,
+/// ex: `as_inner_a` for `#[extract(nested(A))]` on `Outer::Inner(InnerEnum)`.
+///
+/// The inner enum must also derive `EnumExtract`, which puts its marker types in a hidden module next to it.
+pub fn all_nested_functions(
+    enum_name: &Ident,
+    variant: &Variant,
+    variant_attributes: &VariantAttributes,
+    error_types: &ErrorTypes,
+) -> syn::Result<TokenStream> {
+    if variant_attributes.nested.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let variant_name = &variant.ident;
+    let field = match variant.fields.len() {
+        1 => variant.fields.iter().next().expect("no fields on type"),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                variant_name,
+                "`#[extract(nested(...))]` requires a variant with exactly one field",
+            ))
+        }
+    };
+    let inner_type = &field.ty;
+    let module_path = marker_module_path(inner_type)?;
+
+    let (pattern, rebuild) = match &variant.fields {
+        Fields::Named(_) => {
+            let field_name = &field.ident;
+            (
+                quote!(Self::#variant_name { #field_name: inner }),
+                quote!(Self::#variant_name { #field_name: error.value }),
+            )
+        }
+        _ => (
+            quote!(Self::#variant_name(inner)),
+            quote!(Self::#variant_name(error.value)),
+        ),
+    };
+
+    let err_type = &error_types.err_type;
+    let err_value_type = &error_types.err_value_type;
+    let err_value_type_with_generics = &error_types.err_value_type_with_generics;
+    let error_details = error_types.error_details(quote!(self), &[variant_name]);
+    let actual_name = error_types.variant_name(quote!(self));
+    // both levels are named in the message, ex: `expected Outer::Inner::A, got Outer::Inner::B`
+    let error = quote!(
+        #err_type::new(stringify!(#variant_name), #actual_name)#error_details.within(stringify!(#enum_name))
+    );
+
+    let mut tokens = TokenStream::new();
+    for inner_variant in &variant_attributes.nested {
+        let extract =
+            quote!(<#inner_type as enum_extract_error::Extract<#module_path::#inner_variant>>);
+        let is_function = FunctionDef::new_is_nested(enum_name, variant_name, inner_variant);
+        let as_function = FunctionDef::new_as_nested(enum_name, variant_name, inner_variant);
+        let as_mut_function =
+            FunctionDef::new_as_nested_mut(enum_name, variant_name, inner_variant);
+        let into_function = FunctionDef::new_into_nested(enum_name, variant_name, inner_variant);
+        let is_docs = &is_function.docs;
+        let as_docs = &as_function.docs;
+        let as_mut_docs = &as_mut_function.docs;
+        let into_docs = &into_function.docs;
+
+        tokens.extend(quote!(
+            #[doc = #is_docs]
+            #[inline]
+            pub fn #is_function(&self) -> bool {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #pattern => #extract::is_variant(inner),
+                    _ => false,
+                }
+            }
+
+            #[doc = #as_docs]
+            #[inline]
+            #[track_caller]
+            pub fn #as_function(&self) -> ::core::result::Result<#extract::Ref<'_>, #err_type> {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #pattern => #extract::as_variant(inner)
+                        .map_err(|error| error.within(stringify!(#variant_name)).within(stringify!(#enum_name))),
+                    _ => ::core::result::Result::Err(#error),
+                }
+            }
+
+            #[doc = #as_mut_docs]
+            #[inline]
+            #[track_caller]
+            pub fn #as_mut_function(&mut self) -> ::core::result::Result<#extract::Mut<'_>, #err_type> {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #pattern => #extract::as_variant_mut(inner)
+                        .map_err(|error| error.within(stringify!(#variant_name)).within(stringify!(#enum_name))),
+                    _ => ::core::result::Result::Err(#error),
+                }
+            }
+
+            #[doc = #into_docs]
+            #[inline]
+            #[track_caller]
+            pub fn #into_function(self) -> ::core::result::Result<#extract::Owned, #err_value_type_with_generics> {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #pattern => #extract::into_variant(inner).map_err(|error| {
+                        #err_value_type::from_plain_error(
+                            error.source.within(stringify!(#variant_name)).within(stringify!(#enum_name)),
+                            #rebuild,
+                        )
+                    }),
+                    _ => {
+                        let error = #error;
+                        ::core::result::Result::Err(#err_value_type::from_plain_error(error, self))
+                    }
+                }
+            }
+        ));
+    }
+
+    Ok(tokens)
+}
+
+/// Returns the path of the inner enum's hidden marker module, which is next to the enum, see [`hidden_module_ident`].
+pub fn marker_module_path(inner_type: &Type) -> syn::Result<syn::Path> {
+    let Type::Path(type_path) = inner_type else {
+        return Err(syn::Error::new_spanned(
+            inner_type,
+            "expected an enum that derives `EnumExtract`",
+        ));
+    };
+
+    let mut path = type_path.path.clone();
+    let last = path.segments.last_mut().expect("empty type path");
+    last.ident = hidden_module_ident(&last.ident);
+    last.arguments = syn::PathArguments::None;
+
+    Ok(path)
+}
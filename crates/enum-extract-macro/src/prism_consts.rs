@@ -0,0 +1,70 @@
+use heck::ToShoutySnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{Fields, Variant};
+
+use crate::ErrorTypes;
+
+/// Returns the `Prism` constant for the variant, ex: `ManyVariants::ONE` for `ManyVariants::One`.
+///
+/// Only variants with exactly one field have a prism, which focuses on the field itself.
+pub fn variant_prism_const(
+    enum_name: &Ident,
+    variant: &Variant,
+    error_types: &ErrorTypes,
+) -> TokenStream {
+    let variant_name = &variant.ident;
+    let Some(field) = single_field(&variant.fields) else {
+        return TokenStream::new();
+    };
+
+    let const_name = Ident::new(
+        &variant_name.to_string().to_shouty_snake_case(),
+        Span::call_site(),
+    );
+    let field_type = &field.ty;
+    let err_type = &error_types.err_type;
+    let error_details = error_types.error_details(quote!(source), &[variant_name]);
+    let actual_name = error_types.variant_name(quote!(source));
+    let pattern = match &field.ident {
+        Some(field_name) => quote!(Self::#variant_name { #field_name: inner }),
+        None => quote!(Self::#variant_name(inner)),
+    };
+    let docs = format!(
+        "A prism focused on the field of the [`{}::{}`] variant.",
+        enum_name, variant_name
+    );
+
+    let preview = quote!(
+        |source| {
+            #[allow(unreachable_patterns)]
+            match source {
+                #pattern => ::core::result::Result::Ok(inner),
+                _ => ::core::result::Result::Err(
+                    #err_type::new(stringify!(#variant_name), #actual_name)#error_details
+                ),
+            }
+        }
+    );
+
+    quote!(
+        #[doc = #docs]
+        pub const #const_name: enum_extract_error::VariantPrism<Self, #field_type> =
+            enum_extract_error::VariantPrism::new(
+                stringify!(#variant_name),
+                #preview,
+                #preview,
+                #preview,
+                |inner| #pattern,
+            );
+    )
+}
+
+/// Returns the field of the variant if it has exactly one.
+fn single_field(fields: &Fields) -> Option<&syn::Field> {
+    let mut iter = fields.iter();
+    match (iter.next(), iter.next()) {
+        (Some(field), None) => Some(field),
+        _ => None,
+    }
+}
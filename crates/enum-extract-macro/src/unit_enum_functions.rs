@@ -12,7 +12,6 @@ fn unit_enum_is_variant(enum_name: &syn::Ident, variant_name: &syn::Ident) -> To
 
     let function_name = function_def.declaration;
     let docs = function_def.docs;
-    let variant_name = variant_name;
 
     quote!(
         #[doc = #docs]
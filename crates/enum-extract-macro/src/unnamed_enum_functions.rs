@@ -1,25 +1,32 @@
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::{quote, TokenStreamExt};
-use syn::{FieldsUnnamed, Type};
+use quote::{quote, quote_spanned, TokenStreamExt};
+use syn::{spanned::Spanned, FieldsUnnamed};
 
-use crate::function_def::FunctionDef;
+use crate::{
+    attributes::{EnumAttributes, FieldAttributes, VariantAttributes},
+    field_access::{FieldAccess, FieldReturns},
+    function_def::FunctionDef,
+    ErrorTypes,
+};
 
 pub fn all_unnamed_functions(
     enum_name: &Ident,
     variant_name: &Ident,
-    err_type: &Type,
-    err_value_type: &Type,
-    err_value_type_with_generics: &Type,
+    generics: &syn::Generics,
+    error_types: &ErrorTypes,
     fields: &FieldsUnnamed,
-) -> TokenStream {
+    enum_attributes: &EnumAttributes,
+    variant_attributes: &VariantAttributes,
+) -> syn::Result<TokenStream> {
     let context = UnnamedEnumFunctionContext::new(
         enum_name,
         variant_name,
-        err_type,
-        err_value_type,
-        err_value_type_with_generics,
+        generics,
+        error_types,
         fields,
-    );
+        enum_attributes,
+        variant_attributes,
+    )?;
 
     let mut tokens = TokenStream::new();
     tokens.append_all(unnamed_enum_is_variant(&context));
@@ -29,17 +36,30 @@ pub fn all_unnamed_functions(
     tokens.append_all(unnamed_enum_extract_as_variant(&context));
     tokens.append_all(unnamed_enum_extract_as_variant_mut(&context));
     tokens.append_all(unnamed_enum_extract_into_variant(&context));
+    if context.copy {
+        tokens.append_all(unnamed_enum_copied_variant(&context));
+    }
+    if context.clone {
+        tokens.append_all(unnamed_enum_cloned_variant(&context));
+    }
 
-    tokens
+    Ok(tokens)
 }
 
 struct UnnamedEnumFunctionContext<'a> {
     pub enum_name: &'a syn::Ident,
     pub variant_name: &'a syn::Ident,
     pub matches: TokenStream,
-    pub returns_ref: TokenStream,
-    pub returns_mut_ref: TokenStream,
-    pub returns_val: TokenStream,
+    pub returns: FieldReturns,
+    pub fields: Vec<FieldAccess<'a>>,
+    pub copy: bool,
+    pub clone: bool,
+    /// The field types that mention a type parameter, which are bounded on the `copied_` and `cloned_` functions.
+    pub generic_field_types: Vec<&'a syn::Type>,
+    /// Adds user-facing wording and the debug output to errors, see [`ErrorTypes::error_details`].
+    pub error_details: TokenStream,
+    /// The name of the actual variant, see [`ErrorTypes::variant_name`].
+    pub actual_name: TokenStream,
     pub err_type: &'a syn::Type,
     pub err_value_type: &'a syn::Type,
     pub err_value_type_with_generics: &'a syn::Type,
@@ -49,59 +69,54 @@ impl<'a> UnnamedEnumFunctionContext<'a> {
     pub fn new(
         enum_name: &'a Ident,
         variant_name: &'a Ident,
-        err_type: &'a syn::Type,
-        err_value_type: &'a syn::Type,
-        err_value_type_with_generics: &'a syn::Type,
+        generics: &syn::Generics,
+        error_types: &'a ErrorTypes,
         fields: &'a syn::FieldsUnnamed,
-    ) -> Self {
-        let (returns_mut_ref, returns_ref, returns_val, matches) = match fields.unnamed.len() {
-            1 => {
-                let field = fields.unnamed.first().expect("no fields on type");
-
-                let returns = &field.ty;
-                let returns_mut_ref = quote!(&mut #returns);
-                let returns_ref = quote!(&#returns);
-                let returns_val = quote!(#returns);
-                let matches = quote!(inner);
-
-                (returns_mut_ref, returns_ref, returns_val, matches)
-            }
-            0 => (quote!(()), quote!(()), quote!(()), quote!()),
-            _ => {
-                let mut returns_mut_ref = TokenStream::new();
-                let mut returns_ref = TokenStream::new();
-                let mut returns_val = TokenStream::new();
-                let mut matches = TokenStream::new();
-
-                for (i, field) in fields.unnamed.iter().enumerate() {
-                    let rt = &field.ty;
-                    let match_name = Ident::new(&format!("match_{}", i), Span::call_site());
-                    returns_mut_ref.extend(quote!(&mut #rt,));
-                    returns_ref.extend(quote!(&#rt,));
-                    returns_val.extend(quote!(#rt,));
-                    matches.extend(quote!(#match_name,));
-                }
+        enum_attributes: &EnumAttributes,
+        variant_attributes: &VariantAttributes,
+    ) -> syn::Result<Self> {
+        let variant_deref = variant_attributes.deref;
+        let variant_deref_mut = variant_attributes.deref_mut;
+        let mut accesses = Vec::new();
+        for (i, field) in fields.unnamed.iter().enumerate() {
+            let field_attributes = FieldAttributes::parse(&field.attrs)?;
+            let binding = match fields.unnamed.len() {
+                1 => Ident::new("inner", Span::call_site()),
+                _ => Ident::new(&format!("match_{}", i), Span::call_site()),
+            };
 
-                (
-                    quote!((#returns_mut_ref)),
-                    quote!((#returns_ref)),
-                    quote!((#returns_val)),
-                    quote!(#matches),
-                )
-            }
-        };
+            accesses.push(FieldAccess {
+                binding,
+                ty: &field.ty,
+                deref: variant_deref || field_attributes.deref,
+                deref_mut: variant_deref_mut || field_attributes.deref_mut,
+            });
+        }
 
-        Self {
+        let bindings = accesses.iter().map(|field| &field.binding);
+        let matches = quote!(#(#bindings),*);
+        let returns = FieldReturns::new(&accesses);
+        let generic_field_types = accesses
+            .iter()
+            .map(|field| field.ty)
+            .filter(|ty| crate::mentions_type_parameter(ty, generics))
+            .collect();
+
+        Ok(Self {
             enum_name,
             variant_name,
             matches,
-            returns_ref,
-            returns_mut_ref,
-            returns_val,
-            err_type,
-            err_value_type,
-            err_value_type_with_generics,
-        }
+            returns,
+            fields: accesses,
+            copy: enum_attributes.copy || variant_attributes.copy,
+            clone: enum_attributes.clone || variant_attributes.clone,
+            generic_field_types,
+            error_details: error_types.error_details(quote!(self), &[variant_name]),
+            actual_name: error_types.variant_name(quote!(self)),
+            err_type: &error_types.err_type,
+            err_value_type: &error_types.err_value_type,
+            err_value_type_with_generics: &error_types.err_value_type_with_generics,
+        })
     }
 }
 
@@ -127,22 +142,26 @@ fn unnamed_enum_as_variant(context: &UnnamedEnumFunctionContext) -> TokenStream
     let docs = function_def.docs;
     let variant_name = context.variant_name;
     let matches = &context.matches;
-    let returns_ref = &context.returns_ref;
+    let returns_ref = &context.returns.ref_type;
+    let ref_value = &context.returns.ref_value;
     let err_type = context.err_type;
+    let error_details = &context.error_details;
+    let actual_name = &context.actual_name;
 
     quote!(
         #[doc = #docs ]
         #[inline]
+        #[track_caller]
         pub fn #function_name(&self) -> ::core::result::Result<#returns_ref, #err_type> {
             match self {
                 Self::#variant_name(#matches) => {
-                    ::core::result::Result::Ok((#matches))
+                    ::core::result::Result::Ok(#ref_value)
                 }
                 _ => {
                     ::core::result::Result::Err(#err_type::new(
                         stringify!(#variant_name),
-                        self.variant_name(),
-                    ))
+                        #actual_name,
+                    )#error_details)
                 }
             }
         }
@@ -155,22 +174,26 @@ fn unnamed_enum_as_variant_mut(context: &UnnamedEnumFunctionContext) -> TokenStr
     let docs = function_def.docs;
     let variant_name = context.variant_name;
     let matches = &context.matches;
-    let returns_mut_ref = &context.returns_mut_ref;
+    let returns_mut_ref = &context.returns.mut_ref_type;
+    let mut_ref_value = &context.returns.mut_ref_value;
     let err_type = context.err_type;
+    let error_details = &context.error_details;
+    let actual_name = &context.actual_name;
 
     quote!(
         #[doc = #docs ]
         #[inline]
+        #[track_caller]
         pub fn #function_name(&mut self) -> ::core::result::Result<#returns_mut_ref, #err_type> {
             match self {
                 Self::#variant_name(#matches) => {
-                    ::core::result::Result::Ok((#matches))
+                    ::core::result::Result::Ok(#mut_ref_value)
                 }
                 _ => {
                     ::core::result::Result::Err(#err_type::new(
                         stringify!(#variant_name),
-                        self.variant_name(),
-                    ))
+                        #actual_name,
+                    )#error_details)
                 }
             }
         }
@@ -183,24 +206,29 @@ fn unnamed_enum_into_variant(context: &UnnamedEnumFunctionContext) -> TokenStrea
     let docs = function_def.docs;
     let variant_name = context.variant_name;
     let matches = &context.matches;
-    let returns_val = &context.returns_val;
+    let returns_val = &context.returns.val_type;
+    let val_value = &context.returns.val_value;
     let err_type_with_generics = context.err_value_type_with_generics;
-    let err_type = context.err_value_type;
+    let err_value_type = context.err_value_type;
+    let err_type = context.err_type;
+    let error_details = &context.error_details;
+    let actual_name = &context.actual_name;
 
     quote!(
         #[doc = #docs ]
         #[inline]
+        #[track_caller]
         pub fn #function_name(self) -> ::core::result::Result<#returns_val, #err_type_with_generics> {
             match self {
                 Self::#variant_name(#matches) => {
-                    ::core::result::Result::Ok((#matches))
+                    ::core::result::Result::Ok(#val_value)
                 }
                 _ => {
-                    ::core::result::Result::Err(#err_type::new(
+                    let error = #err_type::new(
                         stringify!(#variant_name),
-                        self.variant_name(),
-                        self,
-                    ))
+                        #actual_name,
+                    )#error_details;
+                    ::core::result::Result::Err(#err_value_type::from_plain_error(error, self))
                 }
             }
         }
@@ -214,13 +242,18 @@ fn unnamed_enum_extract_as_variant(context: &UnnamedEnumFunctionContext) -> Toke
     let function_name = function_def.declaration;
     let docs = function_def.docs;
     let alt_function_name = alt_function.declaration;
-    let returns_ref = &context.returns_ref;
+    let returns_ref = &context.returns.ref_type;
 
     quote!(
         #[doc = #docs ]
         #[inline]
+        #[track_caller]
         pub fn #function_name(&self) -> #returns_ref {
-            self.#alt_function_name().unwrap_or_else(|err| panic!("{}", err.to_string()))
+            // matched rather than unwrapped in a closure, so that the panic is reported at the caller
+            match self.#alt_function_name() {
+                ::core::result::Result::Ok(value) => value,
+                ::core::result::Result::Err(err) => panic!("{}", err.to_string()),
+            }
         }
     )
 }
@@ -233,13 +266,18 @@ fn unnamed_enum_extract_as_variant_mut(context: &UnnamedEnumFunctionContext) ->
     let function_name = function_def.declaration;
     let docs = function_def.docs;
     let alt_function_name = alt_function.declaration;
-    let returns_mut_ref = &context.returns_mut_ref;
+    let returns_mut_ref = &context.returns.mut_ref_type;
 
     quote!(
         #[doc = #docs ]
         #[inline]
+        #[track_caller]
         pub fn #function_name(&mut self) -> #returns_mut_ref {
-            self.#alt_function_name().unwrap_or_else(|err| panic!("{}", err.to_string()))
+            // matched rather than unwrapped in a closure, so that the panic is reported at the caller
+            match self.#alt_function_name() {
+                ::core::result::Result::Ok(value) => value,
+                ::core::result::Result::Err(err) => panic!("{}", err.to_string()),
+            }
         }
     )
 }
@@ -252,13 +290,115 @@ fn unnamed_enum_extract_into_variant(context: &UnnamedEnumFunctionContext) -> To
     let function_name = function_def.declaration;
     let docs = function_def.docs;
     let alt_function_name = alt_function.declaration;
-    let returns_val = &context.returns_val;
+    let returns_val = &context.returns.val_type;
 
     quote!(
         #[doc = #docs ]
         #[inline]
+        #[track_caller]
         pub fn #function_name(self) -> #returns_val {
-            self.#alt_function_name().unwrap_or_else(|err| panic!("{}", err.to_string()))
+            // matched rather than unwrapped in a closure, so that the panic is reported at the caller
+            match self.#alt_function_name() {
+                ::core::result::Result::Ok(value) => value,
+                ::core::result::Result::Err(err) => panic!("{}", err.to_string()),
+            }
+        }
+    )
+}
+
+fn unnamed_enum_copied_variant(context: &UnnamedEnumFunctionContext) -> TokenStream {
+    let function_def = FunctionDef::new_copied_variant(context.enum_name, context.variant_name);
+    let function_name = function_def.declaration;
+    let docs = function_def.docs;
+    let variant_name = context.variant_name;
+    let matches = &context.matches;
+    let returns_val = &context.returns.val_type;
+    let field_types = &context.generic_field_types;
+    let err_type = context.err_type;
+    let error_details = &context.error_details;
+    let actual_name = &context.actual_name;
+
+    let copies: Vec<_> = context
+        .fields
+        .iter()
+        .map(|field| {
+            let binding = &field.binding;
+            // copies through a function, so that a field that is not `Copy` is reported at its type
+            quote_spanned!(field.ty.span()=> enum_extract_error::__private::copy(#binding))
+        })
+        .collect();
+    let value = match copies.as_slice() {
+        [copy] => quote!(#copy),
+        _ => quote!((#(#copies,)*)),
+    };
+
+    quote!(
+        #[doc = #docs ]
+        #[inline]
+        #[track_caller]
+        pub fn #function_name(&self) -> ::core::result::Result<#returns_val, #err_type>
+        where
+            #(#field_types: ::core::marker::Copy,)*
+        {
+            match self {
+                Self::#variant_name(#matches) => {
+                    ::core::result::Result::Ok(#value)
+                }
+                _ => {
+                    ::core::result::Result::Err(#err_type::new(
+                        stringify!(#variant_name),
+                        #actual_name,
+                    )#error_details)
+                }
+            }
+        }
+    )
+}
+
+fn unnamed_enum_cloned_variant(context: &UnnamedEnumFunctionContext) -> TokenStream {
+    let function_def = FunctionDef::new_cloned_variant(context.enum_name, context.variant_name);
+    let function_name = function_def.declaration;
+    let docs = function_def.docs;
+    let variant_name = context.variant_name;
+    let matches = &context.matches;
+    let returns_val = &context.returns.val_type;
+    let field_types = &context.generic_field_types;
+    let err_type = context.err_type;
+    let error_details = &context.error_details;
+    let actual_name = &context.actual_name;
+
+    let clones: Vec<_> = context
+        .fields
+        .iter()
+        .map(|field| {
+            let binding = &field.binding;
+            quote_spanned!(field.ty.span()=> ::core::clone::Clone::clone(#binding))
+        })
+        .collect();
+    let value = match clones.as_slice() {
+        [clone] => quote!(#clone),
+        _ => quote!((#(#clones,)*)),
+    };
+
+    quote!(
+        #[doc = #docs ]
+        #[inline]
+        #[track_caller]
+        pub fn #function_name(&self) -> ::core::result::Result<#returns_val, #err_type>
+        where
+            #(#field_types: ::core::clone::Clone,)*
+        {
+            match self {
+                Self::#variant_name(#matches) => {
+                    ::core::result::Result::Ok(#value)
+                }
+                _ => {
+                    ::core::result::Result::Err(#err_type::new(
+                        stringify!(#variant_name),
+                        #actual_name,
+                    )#error_details)
+                }
+            }
         }
     )
 }
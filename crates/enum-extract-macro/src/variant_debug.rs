@@ -0,0 +1,88 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{Fields, Generics, Variant};
+
+use crate::attributes::{FieldAttributes, VariantAttributes};
+
+/// Returns the impl of the hidden `VariantDebug` trait, which returns the `Debug` output of the enum for errors.
+///
+/// It is a trait impl rather than an inherent function, so that it never clashes with the enum's own functions,
+/// and it is parameterized by the deriving type so that a mirror can implement it for a remote enum.
+///
+/// Variants with fields marked with `#[extract(redact)]` are rendered field by field, with those fields hidden.
+/// Everything else uses the enum's own `Debug` implementation, if it has one.
+pub fn variant_debug_impl(
+    enum_name: &Ident,
+    enum_path: &syn::Path,
+    generics: &Generics,
+    variants: &[(&Variant, VariantAttributes)],
+) -> syn::Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut arms = TokenStream::new();
+    for (variant, _) in variants {
+        arms.extend(redacted_variant_arm(variant)?);
+    }
+
+    Ok(quote!(
+        impl #impl_generics enum_extract_error::__private::VariantDebug<#enum_name #ty_generics> for #enum_path #ty_generics #where_clause {
+            #[allow(unused_imports)]
+            fn variant_debug(&self) -> ::core::option::Option<::std::string::String> {
+                use enum_extract_error::__private::{Capture, CaptureDebug, CaptureNone};
+
+                #[allow(unreachable_patterns)]
+                match self {
+                    #arms
+                    _ => (&&Capture(self)).capture_debug(),
+                }
+            }
+        }
+    ))
+}
+
+/// Returns a match arm that renders the variant with its redacted fields hidden,
+/// or nothing if none of its fields are redacted.
+fn redacted_variant_arm(variant: &Variant) -> syn::Result<TokenStream> {
+    let variant_name = &variant.ident;
+
+    let mut bindings = Vec::new();
+    let mut values = Vec::new();
+    let mut redacted_any = false;
+    for (i, field) in variant.fields.iter().enumerate() {
+        let redact = FieldAttributes::parse(&field.attrs)?.redact;
+        let binding = Ident::new(&format!("match_{}", i), Span::call_site());
+
+        values.push(if redact {
+            quote!(::core::option::Option::None)
+        } else {
+            quote!(::core::option::Option::Some((&&Capture(#binding)).capture_debug()?))
+        });
+        bindings.push(binding);
+        redacted_any |= redact;
+    }
+
+    if !redacted_any {
+        return Ok(TokenStream::new());
+    }
+
+    Ok(match &variant.fields {
+        Fields::Unit => TokenStream::new(),
+        Fields::Unnamed(_) => quote!(
+            Self::#variant_name(#(#bindings),*) => ::core::option::Option::Some(
+                enum_extract_error::__private::render_tuple(stringify!(#variant_name), &[#(#values),*]),
+            ),
+        ),
+        Fields::Named(_) => {
+            let names = variant.fields.iter().map(|field| &field.ident);
+            let names_again = names.clone();
+            quote!(
+                Self::#variant_name{ #(#names: #bindings),* } => ::core::option::Option::Some(
+                    enum_extract_error::__private::render_struct(
+                        stringify!(#variant_name),
+                        &[#((stringify!(#names_again), #values)),*],
+                    ),
+                ),
+            )
+        }
+    })
+}
@@ -0,0 +1,121 @@
+use enum_extract_error::MissingFieldError;
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(new)]
+enum ManyVariants {
+    One(u32),
+    Two(u32, i32),
+    Three {
+        one: bool,
+        two: u32,
+        #[extract(default = 3)]
+        three: i64,
+    },
+    Four,
+}
+
+#[test]
+fn new_should_construct_every_kind_of_variant() {
+    assert_eq!(ManyVariants::new_one(1), ManyVariants::One(1));
+    assert_eq!(ManyVariants::new_two(1, 2), ManyVariants::Two(1, 2));
+    assert_eq!(ManyVariants::new_four(), ManyVariants::Four);
+}
+
+#[test]
+fn new_should_use_default_for_fields_with_defaults() {
+    assert_eq!(
+        ManyVariants::new_three(true, 2),
+        ManyVariants::Three {
+            one: true,
+            two: 2,
+            three: 3
+        }
+    );
+}
+
+#[test]
+fn new_should_be_usable_as_function_value() {
+    let many: Vec<ManyVariants> = vec![1, 2].into_iter().map(ManyVariants::new_one).collect();
+
+    assert_eq!(many, vec![ManyVariants::One(1), ManyVariants::One(2)]);
+}
+
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(new, into)]
+enum IntoArguments {
+    #[extract(builder)]
+    Named {
+        name: String,
+        #[extract(default)]
+        count: u64,
+        enabled: bool,
+    },
+}
+
+#[test]
+fn new_should_accept_into_arguments() {
+    assert_eq!(
+        IntoArguments::new_named("name", true),
+        IntoArguments::Named {
+            name: "name".to_string(),
+            count: 0,
+            enabled: true
+        }
+    );
+}
+
+#[test]
+fn builder_should_construct_variant() {
+    let named = IntoArguments::named_builder()
+        .enabled(true)
+        .count(5_u32)
+        .name("name")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        named,
+        IntoArguments::Named {
+            name: "name".to_string(),
+            count: 5,
+            enabled: true
+        }
+    );
+}
+
+#[test]
+fn builder_should_return_error_for_missing_field() {
+    let error = IntoArguments::named_builder()
+        .name("name")
+        .build()
+        .unwrap_err();
+
+    assert_eq!(error, MissingFieldError::new("Named", "enabled"));
+    assert_eq!(error.to_string(), "missing field enabled for Named");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumExtract)]
+enum VariantOptIn {
+    #[extract(new)]
+    One(u32),
+    Two(u32),
+}
+
+#[test]
+fn new_should_be_generated_for_opted_in_variants() {
+    assert_eq!(VariantOptIn::new_one(1), VariantOptIn::One(1));
+}
@@ -0,0 +1,119 @@
+use enum_extract_error::{EnumExtractError, EnumExtractValueError};
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(from)]
+enum ManyVariants {
+    One(u32),
+    Two {
+        two: String,
+    },
+    #[extract(no_from)]
+    Three(u32),
+    Four(u8, u8),
+    Five,
+}
+
+#[test]
+fn from_should_construct_variant_from_payload() {
+    assert_eq!(ManyVariants::from(1_u32), ManyVariants::One(1));
+    assert_eq!(
+        ManyVariants::from("two".to_string()),
+        ManyVariants::Two {
+            two: "two".to_string()
+        }
+    );
+}
+
+#[test]
+fn try_from_should_return_payload_of_matching_variant() {
+    let many = ManyVariants::Two {
+        two: "two".to_string(),
+    };
+
+    let one: u32 = ManyVariants::One(1).try_into().unwrap();
+    let two: &String = (&many).try_into().unwrap();
+
+    assert_eq!(one, 1);
+    assert_eq!(two, "two");
+}
+
+#[test]
+fn try_from_should_return_value_error_for_other_variant() {
+    let error: EnumExtractValueError<ManyVariants> = u32::try_from(ManyVariants::Five).unwrap_err();
+
+    assert_eq!(error.to_string(), "expected One, got Five");
+    assert_eq!(error.value, ManyVariants::Five);
+}
+
+#[test]
+fn try_from_ref_should_return_error_for_other_variant() {
+    let error: EnumExtractError = <&u32>::try_from(&ManyVariants::Three(3)).unwrap_err();
+
+    assert_eq!(error.to_string(), "expected One, got Three");
+}
+
+fn double<T>(value: T) -> std::result::Result<u32, T::Error>
+where
+    T: TryInto<u32>,
+{
+    value.try_into().map(|inner| inner * 2)
+}
+
+#[test]
+fn try_into_should_work_in_generic_code() {
+    assert_eq!(double(ManyVariants::One(2)).unwrap(), 4);
+    assert!(double(ManyVariants::Five).is_err());
+}
+
+#[derive(Debug, PartialEq, EnumExtract)]
+enum OptIn {
+    #[extract(from)]
+    One(u32),
+    Two(u32),
+}
+
+#[test]
+fn from_should_only_be_implemented_for_opted_in_variants() {
+    assert_eq!(OptIn::from(1_u32), OptIn::One(1));
+    assert!(OptIn::Two(2).is_two());
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(from)]
+enum WithGenericPayload<'a, T> {
+    Number(u32),
+    Bare(T),
+    Borrowed(&'a T),
+    Boxed(std::boxed::Box<T>),
+    List(Vec<T>),
+}
+
+#[test]
+fn from_should_skip_bare_type_parameter_payloads() {
+    assert_eq!(
+        WithGenericPayload::<String>::from(1_u32),
+        WithGenericPayload::Number(1)
+    );
+    assert_eq!(
+        WithGenericPayload::from(vec![1_u8]),
+        WithGenericPayload::List(vec![1])
+    );
+
+    let list: std::result::Result<Vec<u8>, _> = WithGenericPayload::Bare(1_u8).try_into();
+    assert!(list.is_err());
+}
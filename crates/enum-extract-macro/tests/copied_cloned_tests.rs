@@ -0,0 +1,127 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+struct NotClone;
+
+#[allow(dead_code)]
+#[derive(EnumExtract)]
+enum ManyVariants {
+    #[extract(copy, clone)]
+    One(u32),
+    #[extract(copy)]
+    Two(u32, i32),
+    #[extract(clone)]
+    Three {
+        one: String,
+        two: Vec<u8>,
+    },
+    Four(NotClone),
+}
+
+#[test]
+fn copied_should_return_copy_of_single_field() {
+    let many = ManyVariants::One(1);
+
+    assert_eq!(many.copied_one().unwrap(), 1);
+    assert_eq!(many.cloned_one().unwrap(), 1);
+}
+
+#[test]
+fn copied_should_return_tuple_for_multiple_fields() {
+    let many = ManyVariants::Two(1, 2);
+
+    assert_eq!(many.copied_two().unwrap(), (1, 2));
+}
+
+#[test]
+fn cloned_should_return_tuple_of_clones_for_multiple_fields() {
+    let many = ManyVariants::Three {
+        one: "one".to_string(),
+        two: vec![2],
+    };
+
+    assert_eq!(
+        many.cloned_three().unwrap(),
+        ("one".to_string(), vec![2_u8])
+    );
+}
+
+#[test]
+fn copied_should_return_error_for_other_variant() {
+    let many = ManyVariants::Four(NotClone);
+
+    let error = many.copied_one().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected One, got Four");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(copy, clone)]
+enum WithGenerics<T> {
+    A(T),
+    B(T),
+}
+
+#[test]
+fn copied_should_only_require_copy_when_called() {
+    let copy = WithGenerics::A(1);
+    let clone = WithGenerics::A("a".to_string());
+
+    assert_eq!(copy.copied_a().unwrap(), 1);
+    assert_eq!(clone.cloned_a().unwrap(), "a");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(clone)]
+enum MixedGenerics<T> {
+    A(T, String),
+    B { items: Vec<T>, count: u32 },
+}
+
+#[test]
+fn cloned_should_only_bound_fields_that_use_generics() {
+    let tuple = MixedGenerics::A(1, "a".to_string());
+    let named = MixedGenerics::B {
+        items: vec![1],
+        count: 2,
+    };
+
+    assert_eq!(tuple.cloned_a().unwrap(), (1, "a".to_string()));
+    assert_eq!(named.cloned_b().unwrap(), (vec![1], 2));
+}
+
+// a field named `copy` must not hide the function that copies the fields
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(copy)]
+enum Reserved {
+    One { copy: u32 },
+    Two { value: u32, copy: bool },
+}
+
+#[test]
+fn copied_should_support_field_named_copy() {
+    assert_eq!(Reserved::One { copy: 1 }.copied_one().unwrap(), 1);
+    assert_eq!(
+        Reserved::Two {
+            value: 2,
+            copy: true
+        }
+        .copied_two()
+        .unwrap(),
+        (2, true)
+    );
+}
@@ -0,0 +1,178 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+use enum_extract_error::{ExtractExt, VariantName};
+
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(markers)]
+enum Body {
+    Json(String),
+    Text(String),
+    Empty,
+}
+
+#[derive(Debug, PartialEq, EnumExtract)]
+struct Event(#[extract(delegate(Json, Text, Empty))] Body);
+
+#[derive(Debug, PartialEq, EnumExtract)]
+struct Message {
+    header: u32,
+    #[extract(delegate(Json))]
+    body: Body,
+}
+
+#[derive(Debug, PartialEq, EnumExtract)]
+struct Tagged<T> {
+    tag: T,
+    #[extract(delegate)]
+    body: Body,
+}
+
+mod shapes {
+    use enum_extract_macro::EnumExtract;
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, EnumExtract)]
+    pub enum Shape {
+        Circle(f64),
+        Square { side: f64 },
+    }
+}
+
+#[derive(Debug, PartialEq, EnumExtract)]
+struct Drawing(#[extract(delegate)] crate::shapes::Shape);
+
+#[test]
+fn newtype_should_forward_is_and_as() {
+    let event = Event(Body::Json("{}".to_string()));
+
+    assert!(event.is_json());
+    assert!(!event.is_text());
+    assert_eq!(event.as_json().unwrap(), &"{}".to_string());
+    assert_eq!(
+        event.as_text().unwrap_err().to_string(),
+        "expected Text, got Json"
+    );
+}
+
+#[test]
+fn newtype_should_forward_as_mut() {
+    let mut event = Event(Body::Json("{".to_string()));
+
+    event.as_json_mut().unwrap().push('}');
+
+    assert_eq!(event, Event(Body::Json("{}".to_string())));
+}
+
+#[test]
+fn newtype_into_should_return_whole_wrapper_on_error() {
+    let event = Event(Body::Empty);
+
+    let error = event.into_json().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected Json, got Empty");
+    assert_eq!(error.value, Event(Body::Empty));
+    assert_eq!(Event(Body::Empty).into_empty().unwrap(), ());
+}
+
+#[test]
+fn struct_into_should_return_whole_wrapper_on_error() {
+    let message = Message {
+        header: 1,
+        body: Body::Text("text".to_string()),
+    };
+
+    let error = message.into_json().unwrap_err();
+
+    assert_eq!(
+        error.value,
+        Message {
+            header: 1,
+            body: Body::Text("text".to_string()),
+        }
+    );
+    assert_eq!(
+        error.source.actual_debug(),
+        std::option::Option::Some("Text(\"text\")")
+    );
+}
+
+#[test]
+fn struct_into_should_return_fields_of_variant() {
+    let message = Message {
+        header: 1,
+        body: Body::Json("{}".to_string()),
+    };
+
+    assert_eq!(message.into_json().unwrap(), "{}".to_string());
+}
+
+#[test]
+fn delegate_should_implement_extract_for_every_variant() {
+    let tagged = Tagged {
+        tag: 1,
+        body: Body::Text("text".to_string()),
+    };
+
+    assert!(tagged.is::<body::Text>());
+    assert_eq!(
+        tagged.extract_ref::<body::Text>().unwrap(),
+        &"text".to_string()
+    );
+
+    let error = tagged.extract_owned::<body::Json>().unwrap_err();
+    assert_eq!(error.value.tag, 1);
+    assert_eq!(error.value.body, Body::Text("text".to_string()));
+}
+
+#[test]
+fn delegate_should_implement_variant_name() {
+    let event = Event(Body::Empty);
+
+    assert_eq!(event.variant_name(), "Empty");
+    assert_eq!(Event::VARIANT_NAMES, &["Json", "Text", "Empty"]);
+}
+
+#[test]
+fn bare_delegate_should_forward_every_variant() {
+    let mut tagged = Tagged {
+        tag: 1,
+        body: Body::Json("{".to_string()),
+    };
+
+    assert!(tagged.is_json());
+    assert!(!tagged.is_empty());
+    tagged.as_json_mut().unwrap().push('}');
+    assert_eq!(tagged.as_json().unwrap(), &"{}".to_string());
+    assert_eq!(
+        tagged.as_text().unwrap_err().to_string(),
+        "expected Text, got Json"
+    );
+
+    let error = tagged.into_empty().unwrap_err();
+    assert_eq!(error.value.tag, 1);
+    assert_eq!(error.value.body, Body::Json("{}".to_string()));
+}
+
+#[test]
+fn bare_delegate_should_forward_variants_of_enum_in_other_module() {
+    let drawing = Drawing(shapes::Shape::Square { side: 2.0 });
+
+    assert!(drawing.is_square());
+    assert_eq!(drawing.as_square().unwrap(), &2.0);
+    assert_eq!(
+        drawing.into_circle().unwrap_err().value,
+        Drawing(shapes::Shape::Square { side: 2.0 })
+    );
+}
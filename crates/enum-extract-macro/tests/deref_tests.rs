@@ -0,0 +1,128 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, PartialEq, EnumExtract)]
+enum Node {
+    Leaf(#[extract(deref, deref_mut)] String),
+    #[extract(deref)]
+    Branch(Box<Node>, Vec<u32>),
+    Named {
+        #[extract(deref)]
+        name: Box<str>,
+        count: u32,
+    },
+    Shared(#[extract(deref)] Rc<String>),
+    Synced(#[extract(deref)] Arc<u32>),
+    Text(#[extract(deref)] Cow<'static, str>),
+    Borrowed(#[extract(deref)] &'static str),
+}
+
+#[test]
+fn as_variant_should_deref_string() {
+    let node = Node::Leaf("leaf".to_string());
+
+    let leaf: &str = node.as_leaf().unwrap();
+
+    assert_eq!(leaf, "leaf");
+}
+
+#[test]
+fn as_variant_should_deref_every_field_of_variant() {
+    let node = Node::Branch(Box::new(Node::Leaf("leaf".to_string())), vec![1, 2]);
+
+    let (inner, items): (&Node, &[u32]) = node.as_branch().unwrap();
+
+    assert_eq!(inner.as_leaf().unwrap(), "leaf");
+    assert_eq!(items, &[1, 2]);
+}
+
+#[test]
+fn as_variant_mut_should_deref_mut() {
+    let mut node = Node::Leaf("leaf".to_string());
+
+    node.as_leaf_mut().unwrap().make_ascii_uppercase();
+
+    assert_eq!(node.as_leaf().unwrap(), "LEAF");
+}
+
+#[test]
+fn into_variant_should_unbox() {
+    let node = Node::Branch(Box::new(Node::Leaf("leaf".to_string())), vec![1]);
+
+    let (inner, items): (Node, Vec<u32>) = node.into_branch().unwrap();
+
+    assert_eq!(inner, Node::Leaf("leaf".to_string()));
+    assert_eq!(items, vec![1]);
+}
+
+#[test]
+fn deref_should_only_apply_to_marked_named_fields() {
+    let node = Node::Named {
+        name: "name".into(),
+        count: 1,
+    };
+
+    let (name, count): (&str, &u32) = node.as_named().unwrap();
+
+    assert_eq!(name, "name");
+    assert_eq!(*count, 1);
+}
+
+#[test]
+fn shared_pointers_should_not_deref_mut() {
+    let mut node = Node::Shared(Rc::new("shared".to_string()));
+
+    let shared: &str = node.as_shared().unwrap();
+    assert_eq!(shared, "shared");
+
+    let shared: &mut Rc<String> = node.as_shared_mut().unwrap();
+    assert_eq!(shared.as_str(), "shared");
+
+    let mut node = Node::Synced(Arc::new(1));
+    let synced: &mut Arc<u32> = node.as_synced_mut().unwrap();
+    assert_eq!(**synced, 1);
+}
+
+#[test]
+fn deref_should_return_error_for_other_variant() {
+    let node = Node::Leaf("leaf".to_string());
+
+    let error = node.as_branch().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected Branch, got Leaf");
+}
+
+#[test]
+fn deref_should_not_deref_mut_without_attribute() {
+    let mut node = Node::Text(Cow::Borrowed("text"));
+
+    let text: &str = node.as_text().unwrap();
+    assert_eq!(text, "text");
+
+    let text: &mut Cow<'static, str> = node.as_text_mut().unwrap();
+    text.to_mut().push('!');
+    assert_eq!(node.as_text().unwrap(), "text!");
+
+    let mut node = Node::Borrowed("borrowed");
+    let borrowed: &str = node.as_borrowed().unwrap();
+    assert_eq!(borrowed, "borrowed");
+
+    let borrowed: &mut &'static str = node.as_borrowed_mut().unwrap();
+    *borrowed = "other";
+    assert_eq!(node.as_borrowed().unwrap(), "other");
+}
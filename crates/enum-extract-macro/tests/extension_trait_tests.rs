@@ -0,0 +1,119 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+mod shapes {
+    use enum_extract_macro::EnumExtract;
+
+    #[derive(Debug, PartialEq, EnumExtract)]
+    #[extract(trait = "ShapeExt", new, prisms)]
+    #[extract(group(Round = [Circle]))]
+    pub enum Shape {
+        Circle(f64),
+        Square { side: f64 },
+        Point,
+    }
+}
+
+use shapes::{Shape, ShapeExt};
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(trait = "WrapperExt")]
+enum Wrapper<'a, T> {
+    Borrowed(&'a T),
+    Owned(T),
+}
+
+#[allow(dead_code)]
+#[derive(EnumExtract)]
+#[extract(remote = "std::cmp::Ordering", trait = "OrderingExt")]
+enum OrderingDef {
+    Less,
+    Equal,
+    Greater,
+}
+
+/// Returns the radius of any value that implements the extension trait.
+fn radius(shape: &impl ShapeExt) -> std::option::Option<f64> {
+    shape.as_circle().ok().copied()
+}
+
+#[test]
+fn functions_should_be_in_extension_trait() {
+    let circle = Shape::Circle(1.0);
+
+    assert!(ShapeExt::is_circle(&circle));
+    assert_eq!(<Shape as ShapeExt>::as_circle(&circle).unwrap(), &1.0);
+    assert_eq!(
+        circle.as_square().unwrap_err().to_string(),
+        "expected Square, got Circle"
+    );
+    assert_eq!(radius(&circle), std::option::Option::Some(1.0));
+}
+
+#[test]
+fn mutable_and_owned_functions_should_be_in_extension_trait() {
+    let mut square = Shape::Square { side: 1.0 };
+
+    *square.as_square_mut().unwrap() += 1.0;
+
+    assert_eq!(square.into_square().unwrap(), 2.0);
+    assert_eq!(Shape::Point.into_circle().unwrap_err().value, Shape::Point);
+}
+
+#[test]
+fn constructors_and_groups_should_be_in_extension_trait() {
+    let circle = <Shape as ShapeExt>::new_circle(1.0);
+
+    assert!(circle.is_round());
+    assert!(matches!(
+        circle.into_round(),
+        std::result::Result::Ok(shapes::Round::Circle(_))
+    ));
+}
+
+#[test]
+fn extension_trait_should_be_dyn_compatible() {
+    let shapes: [&dyn ShapeExt; 2] = [&Shape::Circle(1.0), &Shape::Point];
+
+    assert!(shapes[0].is_circle());
+    assert!(shapes[1].is_point());
+    assert!(shapes[1].as_circle().is_err());
+}
+
+#[test]
+fn prisms_and_variant_names_should_stay_on_enum() {
+    use enum_extract_error::{Optic, VariantName};
+
+    assert_eq!(Shape::CIRCLE.preview(&Shape::Circle(1.0)).unwrap(), &1.0);
+    assert_eq!(Shape::Point.variant_name(), "Point");
+}
+
+#[test]
+fn extension_trait_should_support_generic_enums() {
+    let value = 1;
+    let borrowed = Wrapper::Borrowed(&value);
+
+    assert_eq!(borrowed.as_borrowed().unwrap(), &&1);
+    assert_eq!(
+        borrowed.into_owned().unwrap_err().to_string(),
+        "expected Owned, got Borrowed"
+    );
+}
+
+#[test]
+fn remote_extension_trait_should_use_given_name() {
+    assert!(OrderingExt::is_less(&std::cmp::Ordering::Less));
+    assert!(std::cmp::Ordering::Equal.is_equal());
+}
@@ -0,0 +1,154 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+use enum_extract_error::{EnumExtractError, Extract, ExtractExt};
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(markers)]
+enum ManyVariants {
+    Empty,
+    One(u32),
+    Two(u32, String),
+    Three { value: i64 },
+    Boxed(#[extract(deref)] Box<u32>),
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(markers = generic_markers)]
+enum Generic<'a, T> {
+    Borrowed(&'a T),
+    Owned(T),
+}
+
+/// Returns the fields of the `V` variant, for any enum.
+fn require<V, E>(value: &E) -> std::result::Result<E::Ref<'_>, EnumExtractError>
+where
+    E: Extract<V>,
+{
+    value.extract_ref::<V>()
+}
+
+#[test]
+fn is_should_check_variant_by_marker() {
+    let value = ManyVariants::One(1);
+
+    assert!(value.is::<many_variants::One>());
+    assert!(!value.is::<many_variants::Two>());
+    assert!(!value.is::<many_variants::Empty>());
+}
+
+#[test]
+fn extract_ref_should_return_fields_of_variant() {
+    let one = ManyVariants::One(1);
+    let two = ManyVariants::Two(2, "two".to_string());
+    let three = ManyVariants::Three { value: 3 };
+
+    assert_eq!(one.extract_ref::<many_variants::One>().unwrap(), &1);
+    assert_eq!(
+        two.extract_ref::<many_variants::Two>().unwrap(),
+        (&2, &"two".to_string())
+    );
+    assert_eq!(three.extract_ref::<many_variants::Three>().unwrap(), &3);
+}
+
+#[test]
+fn extract_ref_should_return_error_for_other_variant() {
+    let value = ManyVariants::One(1);
+
+    let error = value.extract_ref::<many_variants::Three>().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected Three, got One");
+}
+
+#[test]
+fn extract_mut_should_modify_fields_of_variant() {
+    let mut value = ManyVariants::Three { value: 3 };
+
+    *value.extract_mut::<many_variants::Three>().unwrap() += 1;
+
+    assert_eq!(value, ManyVariants::Three { value: 4 });
+}
+
+#[test]
+fn extract_owned_should_return_value_in_error() {
+    let value = ManyVariants::Boxed(Box::new(5));
+
+    let error = value.extract_owned::<many_variants::One>().unwrap_err();
+
+    assert_eq!(error.value, ManyVariants::Boxed(Box::new(5)));
+    assert_eq!(
+        ManyVariants::Boxed(Box::new(5))
+            .extract_owned::<many_variants::Boxed>()
+            .unwrap(),
+        5
+    );
+}
+
+#[test]
+fn unit_variant_should_extract_unit() {
+    let mut value = ManyVariants::Empty;
+
+    assert_eq!(value.extract_ref::<many_variants::Empty>().unwrap(), ());
+    assert_eq!(value.extract_mut::<many_variants::Empty>().unwrap(), ());
+    assert_eq!(
+        ManyVariants::One(1)
+            .extract_owned::<many_variants::Empty>()
+            .unwrap_err()
+            .to_string(),
+        "expected Empty, got One"
+    );
+}
+
+#[test]
+fn generic_helper_should_extract_any_variant() {
+    let value = ManyVariants::One(1);
+
+    assert_eq!(require::<many_variants::One, _>(&value).unwrap(), &1);
+    assert!(require::<many_variants::Three, _>(&value).is_err());
+}
+
+#[test]
+fn generic_enum_should_extract_through_renamed_markers() {
+    let inner = 1;
+    let value = Generic::Borrowed(&inner);
+
+    assert_eq!(
+        value.extract_ref::<generic_markers::Borrowed>().unwrap(),
+        &&1
+    );
+    assert_eq!(
+        Generic::<u32>::Owned(2)
+            .extract_owned::<generic_markers::Owned>()
+            .unwrap(),
+        2
+    );
+}
+
+// the public marker module is only generated when asked for, so it must not collide with an existing module
+#[allow(dead_code)]
+mod status {}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+enum Status {
+    Active(u32),
+    Inactive,
+}
+
+#[test]
+fn markers_should_only_be_public_with_markers_attribute() {
+    assert!(Status::Active(1).is_active());
+}
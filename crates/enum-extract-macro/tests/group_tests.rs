@@ -0,0 +1,135 @@
+use enum_extract_error::Expected;
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(group(Numeric = [Int, Float]), group(Textual = [Text, Empty]))]
+enum Value {
+    Int(i64),
+    Float(f64),
+    Text { text: String },
+    Empty,
+}
+
+#[test]
+fn is_group_should_match_every_variant_in_group() {
+    assert!(Value::Int(1).is_numeric());
+    assert!(Value::Float(1.0).is_numeric());
+    assert!(!Value::Empty.is_numeric());
+
+    assert!(Value::Empty.is_textual());
+    assert!(!Value::Int(1).is_textual());
+}
+
+#[test]
+fn as_group_should_return_references() {
+    let value = Value::Text {
+        text: "text".to_string(),
+    };
+
+    let textual = value.as_textual().unwrap();
+
+    assert!(matches!(textual, TextualRef::Text { text } if text == "text"));
+}
+
+#[test]
+fn into_group_should_return_sub_enum() {
+    let value = Value::Float(1.5);
+
+    let numeric = value.into_numeric().unwrap();
+
+    assert!(matches!(numeric, Numeric::Float(inner) if inner == 1.5));
+}
+
+#[test]
+fn sub_enum_should_convert_to_and_from_enum() {
+    let numeric = Numeric::try_from(Value::Int(2)).unwrap();
+    let value = Value::from(numeric);
+
+    assert_eq!(value, Value::Int(2));
+}
+
+#[test]
+fn group_error_should_contain_every_expected_variant() {
+    let value = Value::Empty;
+
+    let error = match value.into_numeric() {
+        std::result::Result::Err(error) => error,
+        _ => panic!("expected an error"),
+    };
+
+    assert_eq!(
+        error.source.expected,
+        Expected::AnyOf {
+            names: vec!["Int".into(), "Float".into()],
+            group: true,
+        }
+    );
+    assert_eq!(error.source.actual, "Empty");
+    assert_eq!(error.value, Value::Empty);
+    assert_eq!(error.to_string(), "expected one of [Int, Float], got Empty");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(group(Both = [A, B]))]
+enum WithGenerics<'a, T> {
+    A(&'a T),
+    B(T),
+    C,
+}
+
+#[test]
+fn groups_should_support_generics() {
+    let inner = 1;
+    let value = WithGenerics::A(&inner);
+
+    assert!(matches!(value.as_both().unwrap(), BothRef::A(&&1)));
+    assert!(matches!(value.into_both().unwrap(), Both::A(&1)));
+    assert!(WithGenerics::<u32>::C.as_both().is_err());
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, EnumExtract)]
+#[extract(group(Numbers = [A, B]), group(Units = [D, E]), group_derive(Debug, Clone, PartialEq))]
+enum PartlyGeneric<T>
+where
+    T: Clone,
+{
+    A(u32),
+    B(u64),
+    C(T),
+    D,
+    E,
+}
+
+#[test]
+fn groups_should_only_use_the_generics_of_their_variants() {
+    let value = PartlyGeneric::<String>::A(1);
+
+    assert_eq!(value.as_numbers().unwrap(), NumbersRef::A(&1));
+    assert_eq!(value.clone().into_numbers().unwrap(), Numbers::A(1));
+    assert_eq!(
+        PartlyGeneric::<String>::from(Numbers::B(2)),
+        PartlyGeneric::B(2)
+    );
+    assert_eq!(PartlyGeneric::<String>::D.as_units().unwrap(), UnitsRef::D);
+    assert_eq!(
+        PartlyGeneric::C("c".to_string())
+            .into_numbers()
+            .unwrap_err()
+            .value,
+        PartlyGeneric::C("c".to_string())
+    );
+}
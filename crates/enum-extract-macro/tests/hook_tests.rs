@@ -0,0 +1,55 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+use std::sync::Mutex;
+
+use enum_extract_error::{set_mismatch_hook, take_mismatch_hook, EnumExtractError};
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(group(Numeric = [Int, Float]), from)]
+enum Value {
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+static MISMATCHES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn record_mismatch(error: &EnumExtractError) {
+    MISMATCHES.lock().unwrap().push(error.to_string());
+}
+
+// the hook is global, so every generated function is checked in one test
+#[test]
+fn generated_functions_should_call_hook() {
+    set_mismatch_hook(record_mismatch);
+
+    let _ = Value::Text("text".to_string()).as_int();
+    let _ = Value::Int(1).into_float();
+    let _ = Value::Text("text".to_string()).as_numeric();
+    let _ = i64::try_from(Value::Float(1.0));
+    let _ = Value::Int(1).as_int();
+
+    take_mismatch_hook();
+    assert_eq!(
+        *MISMATCHES.lock().unwrap(),
+        vec![
+            "expected Int, got Text",
+            "expected Float, got Int",
+            "expected one of [Int, Float], got Text",
+            "expected Int, got Float",
+        ]
+    );
+}
@@ -0,0 +1,162 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+use std::collections::HashMap;
+
+use enum_extract_error::{EnumExtract, VariantName};
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(kind)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Rectangle { width: f64, height: f64 },
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(kind = TokenType)]
+enum Token<T> {
+    Word(T),
+    Number(u32),
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(kind)]
+enum Ambiguous {
+    Kind(u32),
+    Other,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(kind)]
+enum Source {
+    #[extract(
+        display = "a file source",
+        message = "this command requires {expected}, but {actual} was given"
+    )]
+    File(String),
+    #[extract(display = "a URL source")]
+    Url(String),
+    Stdin,
+}
+
+fn count_kinds<E: EnumExtract>(values: &[E]) -> HashMap<E::Kind, usize> {
+    let mut counts = HashMap::new();
+    for value in values {
+        *counts.entry(value.kind()).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[test]
+fn kind_should_match_variant() {
+    assert_eq!(Shape::Empty.kind(), ShapeKind::Empty);
+    assert_eq!(Shape::Circle(1.0).kind(), ShapeKind::Circle);
+    assert_eq!(
+        Shape::Rectangle {
+            width: 1.0,
+            height: 2.0
+        }
+        .kind(),
+        ShapeKind::Rectangle
+    );
+}
+
+#[test]
+fn kinds_should_be_named_after_variants() {
+    let names: Vec<&str> = ShapeKind::ALL
+        .iter()
+        .map(|kind| kind.variant_name())
+        .collect();
+
+    assert_eq!(names, Shape::VARIANT_NAMES);
+    assert_eq!(Shape::VARIANT_COUNT, 3);
+}
+
+#[test]
+fn generic_code_should_compare_kinds() {
+    let shapes = [Shape::Circle(1.0), Shape::Empty, Shape::Circle(2.0)];
+
+    let counts = count_kinds(&shapes);
+
+    assert_eq!(counts[&ShapeKind::Circle], 2);
+    assert_eq!(counts[&ShapeKind::Empty], 1);
+    assert!(!counts.contains_key(&ShapeKind::Rectangle));
+}
+
+#[test]
+fn mismatch_should_describe_expected_kind() {
+    let shape = Shape::Circle(1.0);
+
+    let error = shape.mismatch(ShapeKind::Rectangle);
+
+    assert_eq!(error.to_string(), "expected Rectangle, got Circle");
+}
+
+#[test]
+fn kind_enum_should_be_renamed() {
+    let token = Token::<String>::Number(1);
+
+    assert_eq!(token.kind(), TokenType::Number);
+    assert_eq!(Token::<String>::VARIANT_COUNT, 2);
+}
+
+#[test]
+fn variant_named_kind_should_not_be_ambiguous() {
+    assert_eq!(Ambiguous::Kind(1).kind(), AmbiguousKind::Kind);
+}
+
+// the kind enum is only generated when asked for, so it must not collide with an existing type
+#[allow(dead_code)]
+struct StatusKind;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+enum Status {
+    Active,
+    Inactive,
+}
+
+#[test]
+fn kind_enum_should_only_be_generated_with_kind_attribute() {
+    assert!(Status::Active.is_active());
+}
+
+#[test]
+fn mismatch_should_read_like_the_derived_functions() {
+    let source = Source::Url("https://example.com".to_string());
+
+    let error = source.mismatch(SourceKind::File);
+
+    assert_eq!(error, source.as_file().unwrap_err());
+    assert_eq!(
+        error.to_string(),
+        "this command requires a file source, but a URL source was given"
+    );
+    assert_eq!(error.expected(), "File");
+}
+
+#[test]
+fn mismatch_should_use_display_names_without_message() {
+    let source = Source::Stdin;
+
+    let error = source.mismatch(SourceKind::Url);
+
+    assert_eq!(error.to_string(), "expected a URL source, got Stdin");
+    assert_eq!(SourceKind::Url.variant_display_name(), "a URL source");
+}
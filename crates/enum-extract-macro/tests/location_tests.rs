@@ -0,0 +1,115 @@
+use std::panic::AssertUnwindSafe;
+use std::sync::Mutex;
+
+use enum_extract_error::ExtractExt;
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, EnumExtract)]
+#[extract(group(Numbers = [One, Three]), markers)]
+enum Number {
+    One(u32),
+    Two { inner: u32 },
+    Three,
+}
+
+#[test]
+fn as_variant_should_record_caller_location() {
+    let number = Number::Three;
+
+    let line = line!() + 1;
+    let error = number.as_one().unwrap_err();
+
+    let location = error.location().unwrap();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.line(), line);
+}
+
+#[test]
+fn into_variant_should_record_caller_location() {
+    let number = Number::Three;
+
+    let line = line!() + 1;
+    let error = number.into_two().unwrap_err();
+
+    assert_eq!(error.source.location().unwrap().line(), line);
+    assert!(error.value.is_three());
+}
+
+#[test]
+fn group_functions_should_record_caller_location() {
+    let number = Number::Two { inner: 2 };
+
+    let line = line!() + 1;
+    let error = number.as_numbers().err().unwrap();
+
+    assert_eq!(error.location().unwrap().line(), line);
+}
+
+#[test]
+fn extract_by_marker_should_record_caller_location() {
+    let mut number = Number::Three;
+
+    let line = line!() + 1;
+    let error = number.extract_ref::<number::One>().unwrap_err();
+    assert_eq!(error.location().unwrap().line(), line);
+
+    let line = line!() + 1;
+    let error = number.extract_mut::<number::Two>().unwrap_err();
+    assert_eq!(error.location().unwrap().line(), line);
+
+    let line = line!() + 1;
+    let error = number.extract_owned::<number::One>().unwrap_err();
+    assert_eq!(error.source.location().unwrap().line(), line);
+    assert_eq!(error.source.location().unwrap().file(), file!());
+}
+
+/// Returns the line that `function` panicked at.
+fn panic_line(function: impl FnOnce()) -> u32 {
+    static LINE: Mutex<u32> = Mutex::new(0);
+
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|info| {
+        *LINE.lock().unwrap() = info.location().unwrap().line();
+    }));
+    let result = std::panic::catch_unwind(AssertUnwindSafe(function));
+    std::panic::set_hook(hook);
+
+    assert!(result.is_err());
+    let line = *LINE.lock().unwrap();
+    line
+}
+
+#[test]
+fn extract_functions_should_panic_at_caller_location() {
+    let mut number = Number::Three;
+
+    let line = line!() + 2;
+    let panicked = panic_line(|| {
+        let _ = Number::Three.extract_as_one();
+    });
+    assert_eq!(panicked, line);
+
+    let line = line!() + 2;
+    let panicked = panic_line(|| {
+        let _ = number.extract_as_two_mut();
+    });
+    assert_eq!(panicked, line);
+
+    let line = line!() + 2;
+    let panicked = panic_line(|| {
+        let _ = Number::Three.extract_into_one();
+    });
+    assert_eq!(panicked, line);
+}
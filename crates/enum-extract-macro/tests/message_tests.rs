@@ -0,0 +1,124 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, EnumExtract)]
+#[extract(group(Remote = [Url, Ssh]), from)]
+enum Source {
+    #[extract(
+        display = "a file source",
+        message = "this command requires {expected}, but {actual} was given"
+    )]
+    File(String),
+    #[extract(display = "a URL source", no_from)]
+    Url(String),
+    Ssh,
+}
+
+#[derive(Debug, EnumExtract)]
+#[extract(message = "cannot use {actual} here, expected {expected}")]
+enum Token {
+    Word(String),
+    #[extract(message = "{expected} is required")]
+    Number(u32),
+}
+
+#[test]
+fn variant_message_should_use_display_names() {
+    let source = Source::Url("https://example.com".to_string());
+
+    let error = source.as_file().unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "this command requires a file source, but a URL source was given"
+    );
+}
+
+#[test]
+fn display_names_should_replace_variant_names_without_message() {
+    let source = Source::File("file.txt".to_string());
+
+    let error = source.as_url().unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "expected a URL source, got a file source"
+    );
+}
+
+#[test]
+fn variant_without_display_name_should_use_variant_name() {
+    let source = Source::Ssh;
+
+    let error = source.as_url().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected a URL source, got Ssh");
+}
+
+#[test]
+fn display_names_should_keep_machine_readable_names() {
+    let source = Source::Url("https://example.com".to_string());
+
+    let error = source.into_file().unwrap_err();
+
+    assert_eq!(error.source.actual(), "Url");
+    assert_eq!(error.source.expected().names(), ["File"]);
+    assert_eq!(error.source.actual_display(), "a URL source");
+}
+
+#[test]
+fn group_error_should_use_display_names() {
+    let source = Source::File("file.txt".to_string());
+
+    let error = source.as_remote().err().unwrap();
+
+    assert_eq!(
+        error.to_string(),
+        "expected one of [a URL source, Ssh], got a file source"
+    );
+}
+
+#[test]
+fn try_from_should_use_variant_message() {
+    let source = Source::Ssh;
+
+    let error = String::try_from(source).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "this command requires a file source, but Ssh was given"
+    );
+}
+
+#[test]
+fn enum_message_should_apply_to_variants_without_message() {
+    let token = Token::Number(1);
+
+    let error = token.as_word().unwrap_err();
+
+    assert_eq!(error.to_string(), "cannot use Number here, expected Word");
+}
+
+#[test]
+fn variant_message_should_override_enum_message() {
+    let token = Token::Word("word".to_string());
+
+    let error = token.into_number().unwrap_err();
+
+    assert_eq!(error.to_string(), "Number is required");
+    assert_eq!(
+        error.source.message(),
+        std::option::Option::Some("{expected} is required")
+    );
+}
@@ -106,6 +106,26 @@ fn error_should_contain_expected_and_actual() {
     assert_eq!(error.to_string(), "expected One, got Three");
 }
 
+#[test]
+fn error_should_equal_expected_error() {
+    let many = ManyVariants::Three {
+        one: true,
+        two: 1,
+        three: 2,
+    };
+
+    let error = many.into_one().unwrap_err();
+
+    assert_eq!(
+        error,
+        enum_extract_error::EnumExtractValueError::new("One", "Three", many)
+    );
+    assert_ne!(
+        error.source,
+        enum_extract_error::EnumExtractError::new("Two", "Three")
+    );
+}
+
 #[test]
 fn extract_as_should_not_panic_when_expected_variant_matches_actual() {
     let many = ManyVariants::Three {
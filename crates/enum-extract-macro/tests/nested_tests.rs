@@ -0,0 +1,159 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, PartialEq, EnumExtract)]
+enum InnerEnum {
+    A(u32),
+    B { value: String },
+    Empty,
+}
+
+#[derive(Debug, PartialEq, EnumExtract)]
+enum Outer {
+    #[extract(nested(A, B, Empty))]
+    Inner(InnerEnum),
+    #[extract(nested(A))]
+    Named {
+        inner: InnerEnum,
+    },
+    Other,
+}
+
+mod shapes {
+    use enum_extract_macro::EnumExtract;
+
+    #[derive(Debug, PartialEq, EnumExtract)]
+    #[extract(markers = shape_markers)]
+    pub enum Shape {
+        Circle(f64),
+        Square(f64),
+    }
+}
+
+#[derive(Debug, EnumExtract)]
+enum Drawing {
+    #[extract(nested(Circle))]
+    Shape(shapes::Shape),
+}
+
+#[test]
+fn is_should_check_both_levels() {
+    let outer = Outer::Inner(InnerEnum::A(1));
+
+    assert!(outer.is_inner_a());
+    assert!(!outer.is_inner_b());
+    assert!(!Outer::Other.is_inner_a());
+}
+
+#[test]
+fn as_should_extract_through_both_levels() {
+    let a = Outer::Inner(InnerEnum::A(1));
+    let b = Outer::Inner(InnerEnum::B {
+        value: "b".to_string(),
+    });
+
+    assert_eq!(a.as_inner_a().unwrap(), &1);
+    assert_eq!(b.as_inner_b().unwrap(), &"b".to_string());
+    assert_eq!(Outer::Inner(InnerEnum::Empty).as_inner_empty().unwrap(), ());
+}
+
+#[test]
+fn as_should_qualify_inner_mismatch() {
+    let b = Outer::Inner(InnerEnum::B {
+        value: "b".to_string(),
+    });
+
+    let error = b.as_inner_a().unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "expected Outer::Inner::A, got Outer::Inner::B"
+    );
+    assert_eq!(error.expected().names(), ["A"]);
+    assert_eq!(error.actual(), "B");
+}
+
+#[test]
+fn as_should_report_outer_mismatch() {
+    let error = Outer::Other.as_inner_a().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected Outer::Inner, got Outer::Other");
+}
+
+#[test]
+fn as_mut_should_extract_through_both_levels() {
+    let mut outer = Outer::Inner(InnerEnum::A(1));
+
+    *outer.as_inner_a_mut().unwrap() += 1;
+
+    assert_eq!(outer, Outer::Inner(InnerEnum::A(2)));
+}
+
+#[test]
+fn into_should_return_outer_value_on_inner_mismatch() {
+    let outer = Outer::Inner(InnerEnum::Empty);
+
+    let error = outer.into_inner_a().unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "expected Outer::Inner::A, got Outer::Inner::Empty"
+    );
+    assert_eq!(error.value, Outer::Inner(InnerEnum::Empty));
+}
+
+#[test]
+fn into_should_return_outer_value_on_outer_mismatch() {
+    let error = Outer::Other.into_inner_a().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected Outer::Inner, got Outer::Other");
+    assert_eq!(error.value, Outer::Other);
+}
+
+#[test]
+fn nested_should_support_named_variants() {
+    let outer = Outer::Named {
+        inner: InnerEnum::A(1),
+    };
+
+    assert_eq!(outer.as_named_a().unwrap(), &1);
+    assert_eq!(outer.into_named_a().unwrap(), 1);
+    assert_eq!(
+        Outer::Named {
+            inner: InnerEnum::Empty
+        }
+        .into_named_a()
+        .unwrap_err()
+        .value,
+        Outer::Named {
+            inner: InnerEnum::Empty
+        }
+    );
+}
+
+#[test]
+fn nested_should_support_enums_in_other_modules() {
+    let drawing = Drawing::Shape(shapes::Shape::Square(1.0));
+
+    assert_eq!(
+        drawing.as_shape_circle().unwrap_err().to_string(),
+        "expected Drawing::Shape::Circle, got Drawing::Shape::Square"
+    );
+    assert_eq!(
+        Drawing::Shape(shapes::Shape::Circle(1.0))
+            .into_shape_circle()
+            .unwrap(),
+        1.0
+    );
+}
@@ -0,0 +1,54 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(EnumExtract)]
+enum Handler {
+    Callback(Box<dyn Fn() -> u32>),
+    Value(u32),
+}
+
+fn into_value(handler: Handler) -> std::result::Result<u32, Box<dyn std::error::Error>> {
+    let value = handler.into_value()?;
+    std::result::Result::Ok(value)
+}
+
+#[test]
+fn into_variant_should_convert_to_boxed_error() {
+    let handler = Handler::Callback(Box::new(|| 1));
+
+    let error = into_value(handler).unwrap_err();
+
+    assert_eq!(error.to_string(), "expected Value, got Callback");
+}
+
+#[test]
+fn into_variant_should_unwrap_without_debug() {
+    let handler = Handler::Value(1);
+
+    assert_eq!(handler.into_value().unwrap(), 1);
+}
+
+#[test]
+fn debug_should_name_value_type() {
+    let handler = Handler::Callback(Box::new(|| 1));
+
+    let error = match handler.into_value() {
+        std::result::Result::Err(error) => error,
+        _ => panic!("expected an error"),
+    };
+
+    assert!(format!("{:?}", error).ends_with("value: non_debug_tests::Handler }"));
+    assert_eq!((error.value.into_callback().unwrap())(), 1);
+}
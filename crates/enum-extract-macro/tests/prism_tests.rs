@@ -0,0 +1,202 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+use enum_extract_error::{FieldLens, Optic, Prism};
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(prisms)]
+enum ManyVariants {
+    Empty,
+    One(u32),
+    Two(u32, String),
+    Three { value: i64 },
+    Boxed(#[extract(deref)] Box<u32>),
+}
+
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(prisms)]
+enum Body {
+    Json(Json),
+    #[extract(display = "plain text")]
+    Text(String),
+}
+
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(prisms)]
+enum Json {
+    Number(i64),
+    String(String),
+}
+
+#[derive(Debug, PartialEq)]
+struct Outer {
+    body: Body,
+}
+
+const BODY: FieldLens<Outer, Body> = FieldLens::new(
+    "body",
+    |outer| &outer.body,
+    |outer| &mut outer.body,
+    |outer| outer.body,
+);
+
+#[derive(Debug, PartialEq, EnumExtract)]
+#[extract(lenses)]
+struct Request {
+    id: u32,
+    body: Body,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(prisms)]
+enum Generic<'a, T> {
+    Borrowed(&'a T),
+    Owned(T),
+}
+
+#[test]
+fn prism_should_preview_unnamed_variant() {
+    let one = ManyVariants::One(1);
+    let three = ManyVariants::Three { value: 3 };
+
+    assert_eq!(ManyVariants::ONE.preview(&one).unwrap(), &1);
+    assert_eq!(
+        ManyVariants::ONE.preview(&three).unwrap_err().to_string(),
+        "expected One, got Three"
+    );
+}
+
+#[test]
+fn prism_should_preview_named_variant() {
+    let three = ManyVariants::Three { value: 3 };
+
+    assert_eq!(ManyVariants::THREE.preview(&three).unwrap(), &3);
+}
+
+#[test]
+fn prism_should_focus_on_field_without_deref() {
+    let boxed = ManyVariants::Boxed(Box::new(4));
+
+    assert_eq!(ManyVariants::BOXED.preview(&boxed).unwrap(), &Box::new(4));
+}
+
+#[test]
+fn prism_should_preview_mut_and_into_preview() {
+    let mut three = ManyVariants::Three { value: 3 };
+
+    *ManyVariants::THREE.preview_mut(&mut three).unwrap() += 1;
+
+    assert_eq!(ManyVariants::THREE.into_preview(three).unwrap(), 4);
+    assert!(ManyVariants::ONE.into_preview(ManyVariants::Empty).is_err());
+}
+
+#[test]
+fn prism_should_review_variant() {
+    assert_eq!(ManyVariants::ONE.review(1), ManyVariants::One(1));
+    assert_eq!(
+        ManyVariants::THREE.review(3),
+        ManyVariants::Three { value: 3 }
+    );
+}
+
+#[test]
+fn prism_should_have_variant_name() {
+    assert_eq!(ManyVariants::ONE.name(), "One");
+}
+
+#[test]
+fn prism_error_should_use_display_name_and_debug_output() {
+    let error = Body::JSON
+        .preview(&Body::Text("text".to_string()))
+        .unwrap_err();
+
+    assert_eq!(error.to_string(), "expected Json, got plain text");
+    assert_eq!(
+        error.actual_debug(),
+        std::option::Option::Some("Text(\"text\")")
+    );
+}
+
+#[test]
+fn composed_prisms_should_report_path_on_failure() {
+    let outer = Outer {
+        body: Body::Json(Json::String("one".to_string())),
+    };
+    let number = BODY.then(Body::JSON).then(Json::NUMBER);
+
+    let error = number.preview(&outer).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "at body.Json: expected Number, got String"
+    );
+}
+
+#[test]
+fn composed_prisms_should_review_nested_value() {
+    let number = Body::JSON.then(Json::NUMBER);
+
+    assert_eq!(number.review(1), Body::Json(Json::Number(1)));
+}
+
+#[test]
+fn prism_should_support_generic_enums() {
+    let value = 1;
+    let borrowed = Generic::Borrowed(&value);
+
+    assert_eq!(Generic::<u32>::BORROWED.preview(&borrowed).unwrap(), &&1);
+    assert!(Generic::<u32>::OWNED.preview(&borrowed).is_err());
+}
+
+// the prisms are only generated when asked for, so they must not collide with the enum's own constants
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+enum Limit {
+    Upper(u32),
+    Lower(u32),
+}
+
+impl Limit {
+    const UPPER: u32 = 10;
+}
+
+#[test]
+fn prisms_should_only_be_generated_with_prisms_attribute() {
+    assert_eq!(Limit::UPPER, 10);
+}
+
+#[test]
+fn derived_lenses_should_compose_with_prisms() {
+    let mut request = Request {
+        id: 1,
+        body: Body::Json(Json::Number(1)),
+    };
+    let number = Request::BODY.then(Body::JSON).then(Json::NUMBER);
+
+    assert_eq!(Request::ID.preview(&request).unwrap(), &1);
+    assert_eq!(number.preview(&request).unwrap(), &1);
+    *number.preview_mut(&mut request).unwrap() += 1;
+    assert_eq!(number.into_preview(request).unwrap(), 2);
+
+    let request = Request {
+        id: 2,
+        body: Body::Json(Json::String("one".to_string())),
+    };
+    assert_eq!(
+        number.preview(&request).unwrap_err().to_string(),
+        "at body.Json: expected Number, got String"
+    );
+}
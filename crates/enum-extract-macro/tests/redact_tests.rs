@@ -0,0 +1,135 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+#[extract(group(Authenticated = [Token, Key]))]
+enum Credentials {
+    Anonymous,
+    Token(#[extract(redact)] String, u32),
+    Login {
+        user: String,
+        #[extract(redact)]
+        password: String,
+    },
+    Key(Vec<u8>),
+}
+
+#[allow(dead_code)]
+#[derive(EnumExtract)]
+enum NotDebug {
+    One(u32),
+    Two(u32),
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+enum Generic<T> {
+    One(T),
+    Two(u32),
+}
+
+#[test]
+fn error_should_record_debug_output_of_value() {
+    let credentials = Credentials::Key(vec![1, 2]);
+
+    let error = credentials.as_token().unwrap_err();
+
+    assert_eq!(
+        error.actual_debug(),
+        std::option::Option::Some("Key([1, 2])")
+    );
+}
+
+#[test]
+fn error_should_hide_redacted_tuple_fields() {
+    let credentials = Credentials::Token("secret".to_string(), 3);
+
+    let error = credentials.into_key().unwrap_err();
+
+    assert_eq!(
+        error.source.actual_debug(),
+        std::option::Option::Some("Token(<redacted>, 3)")
+    );
+}
+
+#[test]
+fn error_should_hide_redacted_named_fields() {
+    let credentials = Credentials::Login {
+        user: "user".to_string(),
+        password: "secret".to_string(),
+    };
+
+    let error = credentials.as_key().unwrap_err();
+
+    assert_eq!(
+        error.actual_debug(),
+        std::option::Option::Some(r#"Login { user: "user", password: <redacted> }"#)
+    );
+}
+
+#[test]
+fn group_error_should_record_debug_output_of_value() {
+    let credentials = Credentials::Anonymous;
+
+    let error = credentials.as_authenticated().err().unwrap();
+
+    assert_eq!(error.actual_debug(), std::option::Option::Some("Anonymous"));
+}
+
+#[test]
+fn error_should_not_record_value_without_debug() {
+    let value = NotDebug::One(1);
+
+    let error = value.as_two().unwrap_err();
+
+    assert!(error.actual_debug().is_none());
+}
+
+#[test]
+fn error_should_not_record_value_with_generic_debug() {
+    let value = Generic::<u32>::Two(2);
+
+    let error = value.as_one().unwrap_err();
+
+    assert!(error.actual_debug().is_none());
+    assert_eq!(error.to_string(), "expected One, got Two");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+enum OwnVariantDebug {
+    One(u32),
+    Two(u32),
+}
+
+impl OwnVariantDebug {
+    // must not collide with the `Debug` output recorded in errors
+    pub fn variant_debug(&self) -> u8 {
+        match self {
+            OwnVariantDebug::One(_) => 1,
+            OwnVariantDebug::Two(_) => 2,
+        }
+    }
+}
+
+#[test]
+fn error_should_record_debug_output_when_enum_has_variant_debug_function() {
+    let value = OwnVariantDebug::Two(2);
+
+    let error = value.as_one().unwrap_err();
+
+    assert_eq!(value.variant_debug(), 2);
+    assert_eq!(error.actual_debug(), std::option::Option::Some("Two(2)"));
+}
@@ -0,0 +1,135 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+use std::{
+    cmp::Ordering,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+#[allow(dead_code)]
+#[derive(EnumExtract)]
+#[extract(remote = "std::cmp::Ordering")]
+enum OrderingDef {
+    Less,
+    Equal,
+    Greater,
+}
+
+#[allow(dead_code)]
+#[derive(EnumExtract)]
+#[extract(remote = "std::option::Option", new)]
+#[extract(group(Any = [None, Some]))]
+enum OptionDef<T> {
+    None,
+    #[extract(display = "a value")]
+    Some(T),
+}
+
+// `std::io::ErrorKind` is `#[non_exhaustive]`, and most of its variants are left out of the mirror
+#[allow(dead_code)]
+#[derive(EnumExtract)]
+#[extract(remote = "std::io::ErrorKind")]
+enum ErrorKindDef {
+    NotFound,
+    PermissionDenied,
+}
+
+// only `V4` is mirrored, so `V6` is unknown
+#[allow(dead_code)]
+#[derive(EnumExtract)]
+#[extract(remote = "std::net::IpAddr")]
+enum IpAddrDef {
+    V4(Ipv4Addr),
+}
+
+#[test]
+fn remote_unit_functions_should_be_in_extension_trait() {
+    let ordering = Ordering::Less;
+
+    assert!(OrderingDefExt::is_less(&ordering));
+    assert!(!ordering.is_greater());
+}
+
+#[test]
+fn remote_errors_should_use_variant_names() {
+    let value: std::option::Option<u32> = std::option::Option::None;
+
+    let error = value.as_some().unwrap_err();
+
+    assert_eq!(error.expected().names(), ["Some"]);
+    assert_eq!(error.actual(), "None");
+}
+
+#[test]
+fn remote_generic_functions_should_extract_fields() {
+    let mut value = std::option::Option::Some(1);
+
+    assert_eq!(value.as_some().unwrap(), &1);
+    assert_eq!(
+        std::option::Option::<u32>::None
+            .as_some()
+            .unwrap_err()
+            .to_string(),
+        "expected a value, got None"
+    );
+    *value.as_some_mut().unwrap() += 1;
+    assert_eq!(value.into_some().unwrap(), 2);
+}
+
+#[test]
+fn remote_errors_should_use_display_names_and_keep_value() {
+    let value: std::option::Option<u32> = std::option::Option::None;
+
+    let error = value.into_some().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected a value, got None");
+    assert_eq!(error.value, std::option::Option::None);
+}
+
+#[test]
+fn remote_constructors_should_build_remote_enum() {
+    assert_eq!(
+        <std::option::Option<u32> as OptionDefExt<u32>>::new_some(1),
+        std::option::Option::Some(1)
+    );
+}
+
+#[test]
+fn remote_groups_should_convert_remote_enum() {
+    let value = std::option::Option::Some(1);
+
+    assert!(value.is_any());
+    assert!(matches!(
+        value.into_any(),
+        std::result::Result::Ok(Any::Some(1))
+    ));
+}
+
+#[test]
+fn remote_unknown_variants_should_be_named_unknown() {
+    let kind = std::io::ErrorKind::Other;
+
+    assert!(!kind.is_not_found());
+    assert!(std::io::ErrorKind::NotFound.is_not_found());
+}
+
+#[test]
+fn remote_errors_should_name_unknown_variants() {
+    let address = IpAddr::V6(Ipv6Addr::LOCALHOST);
+
+    let error = address.as_v4().unwrap_err();
+
+    assert_eq!(error.to_string(), "expected V4, got <unknown>");
+    assert_eq!(error.actual(), "<unknown>");
+}
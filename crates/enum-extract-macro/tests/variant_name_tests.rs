@@ -0,0 +1,98 @@
+use enum_extract_macro::EnumExtract;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+use enum_extract_error::VariantName;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    #[extract(display = "a rectangle")]
+    Rectangle {
+        width: f64,
+        height: f64,
+    },
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumExtract)]
+enum Named {
+    One(u32),
+    Two(u32),
+}
+
+impl Named {
+    // must not collide with the `VariantName` implementation
+    fn variant_name(&self) -> u32 {
+        match self {
+            Named::One(_) => 1,
+            Named::Two(_) => 2,
+        }
+    }
+}
+
+#[derive(Debug, EnumExtract)]
+enum EmptyEnum {}
+
+fn describe(value: &impl VariantName) -> String {
+    format!(
+        "{} ({})",
+        value.variant_name(),
+        value.variant_display_name()
+    )
+}
+
+#[test]
+fn variant_name_should_name_each_variant() {
+    assert_eq!(Shape::Empty.variant_name(), "Empty");
+    assert_eq!(Shape::Circle(1.0).variant_name(), "Circle");
+    assert_eq!(
+        Shape::Rectangle {
+            width: 1.0,
+            height: 2.0
+        }
+        .variant_name(),
+        "Rectangle"
+    );
+}
+
+#[test]
+fn variant_names_should_list_variants_in_order() {
+    assert_eq!(Shape::VARIANT_NAMES, ["Empty", "Circle", "Rectangle"]);
+    assert!(EmptyEnum::VARIANT_NAMES.is_empty());
+}
+
+#[test]
+fn variant_display_name_should_default_to_variant_name() {
+    let rectangle = Shape::Rectangle {
+        width: 1.0,
+        height: 2.0,
+    };
+
+    assert_eq!(describe(&Shape::Circle(1.0)), "Circle (Circle)");
+    assert_eq!(describe(&rectangle), "Rectangle (a rectangle)");
+}
+
+#[test]
+fn inherent_variant_name_should_not_collide_with_trait() {
+    let named = Named::Two(2);
+
+    assert_eq!(named.variant_name(), 2);
+    assert_eq!(VariantName::variant_name(&named), "Two");
+    assert_eq!(
+        named.as_one().unwrap_err().to_string(),
+        "expected One, got Two"
+    );
+}